@@ -3,8 +3,11 @@
 //! This module detects strongly connected components (SCCs) in the
 //! dependency graph to identify circular dependencies.
 
+use std::collections::HashMap;
+
 use petgraph::algo::tarjan_scc;
 
+use super::DependencyGraphLike;
 use crate::graph::DependencyGraph;
 
 /// Detects cycles in the dependency graph.
@@ -20,35 +23,51 @@ use crate::graph::DependencyGraph;
 ///
 /// A vector of cycles, where each cycle is a vector of file IDs
 /// in the order they form the cycle.
-pub fn detect_cycles(graph: &DependencyGraph) -> Vec<Vec<String>> {
-    let inner = graph.inner();
-    let node_index = graph.node_index();
-
+pub fn detect_cycles<G: DependencyGraphLike>(graph: &G) -> Vec<Vec<String>> {
     // Find strongly connected components
-    let sccs = tarjan_scc(inner);
+    let sccs = tarjan_scc(graph.graph());
 
     // Filter to SCCs with more than one node (actual cycles)
-    let mut cycles = Vec::new();
-    for scc in sccs {
-        if scc.len() > 1 {
-            // Convert node indices to file IDs
-            let cycle: Vec<String> = scc
+    // and convert node indices to file IDs
+    sccs.into_iter()
+        .filter(|scc| scc.len() > 1)
+        .map(|scc| scc.iter().map(|&idx| graph.node_id(idx).to_string()).collect())
+        .collect()
+}
+
+/// Marks edges whose endpoints belong to the same cycle.
+///
+/// Each edge's `cycle_index` is set to the position of its cycle in
+/// the graph's detected cycles, so [`detect_cycles`] results must be
+/// stored with `set_cycles` first. Other edges are cleared.
+///
+/// # Arguments
+///
+/// * `graph` - The dependency graph to update
+pub fn mark_cycle_edges(graph: &mut DependencyGraph) {
+    let membership: HashMap<_, usize> = graph
+        .get_cycles()
+        .iter()
+        .enumerate()
+        .flat_map(|(i, cycle)| {
+            cycle
                 .iter()
-                .filter_map(|idx| {
-                    node_index
-                        .iter()
-                        .find(|(_, &i)| i == *idx)
-                        .map(|(id, _)| id.clone())
-                })
-                .collect();
+                .filter_map(|id| graph.node_index().get(id).copied())
+                .map(move |idx| (idx, i))
+        })
+        .collect();
 
-            if !cycle.is_empty() {
-                cycles.push(cycle);
-            }
-        }
+    let inner = graph.inner_mut();
+    for edge in inner.edge_indices() {
+        let Some((from, to)) = inner.edge_endpoints(edge) else {
+            continue;
+        };
+        let cycle = match (membership.get(&from), membership.get(&to)) {
+            (Some(a), Some(b)) if a == b => Some(*a),
+            _ => None,
+        };
+        inner[edge].cycle_index = cycle;
     }
-
-    cycles
 }
 
 #[cfg(test)]
@@ -0,0 +1,94 @@
+//! Duplicate basename detection.
+//!
+//! This module groups files that resolve to the same module name in
+//! different directories (e.g. `_variables.scss` in several folders).
+//! Ambiguous names are a frequent source of imports resolving to the
+//! wrong file, especially when load paths are involved.
+
+use std::collections::BTreeMap;
+
+use petgraph::Direction;
+
+use crate::graph::DependencyGraph;
+
+/// A group of files sharing the same module name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateBasename {
+    /// Module name without partial underscore or extension.
+    pub basename: String,
+    /// Files with this module name, sorted by file ID.
+    pub files: Vec<DuplicateFile>,
+}
+
+/// A file in a duplicate basename group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateFile {
+    /// File ID.
+    pub file: String,
+    /// IDs of the files that import this file, sorted.
+    pub imported_by: Vec<String>,
+}
+
+/// Detects files that share a module name across directories.
+///
+/// `_foo.scss`, `foo.scss`, and `foo.sass` all count as module `foo`.
+/// Index files are skipped, since every directory module has one.
+///
+/// # Arguments
+///
+/// * `graph` - The dependency graph to analyze
+///
+/// # Returns
+///
+/// A vector of groups with more than one file, sorted by basename.
+pub fn detect_duplicate_basenames(graph: &DependencyGraph) -> Vec<DuplicateBasename> {
+    let inner = graph.inner();
+
+    let mut groups: BTreeMap<String, Vec<DuplicateFile>> = BTreeMap::new();
+    for (id, &idx) in graph.node_index() {
+        let basename = module_name(id);
+        if basename == "index" {
+            continue;
+        }
+
+        let mut imported_by: Vec<String> = inner
+            .neighbors_directed(idx, Direction::Incoming)
+            .map(|n| inner[n].id.clone())
+            .collect();
+        imported_by.sort();
+
+        groups.entry(basename).or_default().push(DuplicateFile {
+            file: id.clone(),
+            imported_by,
+        });
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, files)| files.len() > 1)
+        .map(|(basename, mut files)| {
+            files.sort_by(|a, b| a.file.cmp(&b.file));
+            DuplicateBasename { basename, files }
+        })
+        .collect()
+}
+
+/// Returns the Sass module name of a file ID.
+fn module_name(id: &str) -> String {
+    let name = id.rsplit('/').next().unwrap_or(id);
+    let name = name.strip_prefix('_').unwrap_or(name);
+    let name = name.split('.').next().unwrap_or(name);
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_name_strips_partial_and_extension() {
+        assert_eq!(module_name("src/base/_variables.scss"), "variables");
+        assert_eq!(module_name("theme/variables.sass"), "variables");
+        assert_eq!(module_name("main.scss"), "main");
+    }
+}
@@ -0,0 +1,135 @@
+//! Entry point analysis.
+//!
+//! This module finds entry points that are also reachable from another
+//! entry point. Such entries are already part of another bundle, and their
+//! entry point metrics (depth 0, `EntryPoint` flag) hide where they really
+//! sit in the graph.
+//!
+//! It also finds dead entry points: bundles whose compiled output would
+//! be empty because neither the entry nor anything it loads emits CSS.
+
+use std::collections::HashSet;
+
+use petgraph::Direction;
+
+use crate::graph::DependencyGraph;
+
+/// An entry point that is reachable from other entry points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedundantEntry {
+    /// File ID of the redundant entry point.
+    pub entry: String,
+    /// File IDs of the entry points that (transitively) depend on it.
+    pub reachable_from: Vec<String>,
+}
+
+/// Detects entry points reachable from another entry point.
+///
+/// # Arguments
+///
+/// * `graph` - The dependency graph to analyze
+///
+/// # Returns
+///
+/// A vector of redundant entries, sorted by file ID.
+pub fn detect_redundant_entries(graph: &DependencyGraph) -> Vec<RedundantEntry> {
+    let inner = graph.inner();
+    let node_index = graph.node_index();
+
+    let mut entries: Vec<&String> = graph.entry_points().iter().collect();
+    entries.sort();
+
+    let mut redundant = Vec::new();
+    for entry in &entries {
+        let Some(&start) = node_index.get(entry.as_str()) else {
+            continue;
+        };
+
+        // Walk the dependents of this entry
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(current) = stack.pop() {
+            for neighbor in inner.neighbors_directed(current, Direction::Incoming) {
+                if visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        let reachable_from: Vec<String> = entries
+            .iter()
+            .filter(|other| *other != entry)
+            .filter(|other| {
+                node_index
+                    .get(other.as_str())
+                    .map(|idx| visited.contains(idx))
+                    .unwrap_or(false)
+            })
+            .map(|other| other.to_string())
+            .collect();
+
+        if !reachable_from.is_empty() {
+            redundant.push(RedundantEntry {
+                entry: entry.to_string(),
+                reachable_from,
+            });
+        }
+    }
+
+    redundant
+}
+
+/// Detects entry points whose compiled output would likely be empty.
+///
+/// An entry is dead when neither it nor any file it transitively loads
+/// emits CSS according to [`Parser::emits_css`](crate::parser::Parser::emits_css).
+/// Entries that load a file with unresolved targets are never reported,
+/// since the missing files may well emit CSS.
+///
+/// # Returns
+///
+/// The IDs of dead entry points, sorted.
+pub fn detect_dead_entries(graph: &DependencyGraph) -> Vec<String> {
+    let inner = graph.inner();
+    let node_index = graph.node_index();
+
+    let mut dead: Vec<String> = graph
+        .entry_points()
+        .iter()
+        .filter(|entry| {
+            let Some(&start) = node_index.get(entry.as_str()) else {
+                return false;
+            };
+
+            let mut visited = HashSet::new();
+            let mut stack = vec![start];
+            while let Some(current) = stack.pop() {
+                if !visited.insert(current) {
+                    continue;
+                }
+                if inner[current].emits_css || inner[current].has_unresolved {
+                    return false;
+                }
+                stack.extend(inner.neighbors_directed(current, Direction::Outgoing));
+            }
+
+            true
+        })
+        .cloned()
+        .collect();
+
+    dead.sort();
+    dead
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_no_redundant_entries_empty_graph() {
+        let graph = DependencyGraph::new();
+        assert!(detect_redundant_entries(&graph).is_empty());
+        assert!(detect_dead_entries(&graph).is_empty());
+    }
+}
@@ -3,7 +3,11 @@
 //! This module assigns flags to nodes based on their metrics
 //! and position in the graph.
 
-use crate::graph::{DependencyGraph, NodeFlag};
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+
+use crate::graph::{DependencyGraph, NodeFlag, NodeMetrics};
 
 /// Thresholds for flag assignment.
 #[derive(Debug, Clone)]
@@ -34,50 +38,65 @@ impl Default for FlagThresholds {
 /// Note: `EntryPoint` and `Orphan` flags are assigned during graph construction.
 pub fn assign_flags(graph: &mut DependencyGraph, thresholds: &FlagThresholds) {
     // Collect cycle members
-    let cycle_members: std::collections::HashSet<String> = graph
-        .get_cycles()
-        .iter()
-        .flatten()
-        .cloned()
-        .collect();
-
-    // Collect node IDs for iteration
-    let node_ids: Vec<String> = graph.nodes().map(|(id, _)| id.clone()).collect();
-
-    for id in node_ids {
-        let (fan_in, fan_out, is_in_cycle) = {
-            let node = graph.get_node(&id).unwrap();
-            (
-                node.metrics.fan_in,
-                node.metrics.fan_out,
-                cycle_members.contains(&id),
-            )
-        };
+    let cycle_members: HashSet<String> = graph.get_cycles().iter().flatten().cloned().collect();
 
-        if let Some(node) = graph.get_node_mut(&id) {
-            // Leaf: no outgoing dependencies
-            if fan_out == 0 {
-                node.add_flag(NodeFlag::Leaf);
-            }
-
-            // High fan-in
-            if fan_in >= thresholds.high_fan_in {
-                node.add_flag(NodeFlag::HighFanIn);
-            }
-
-            // High fan-out
-            if fan_out >= thresholds.high_fan_out {
-                node.add_flag(NodeFlag::HighFanOut);
-            }
-
-            // In cycle
-            if is_in_cycle {
-                node.add_flag(NodeFlag::InCycle);
-            }
+    for node in graph.inner_mut().node_weights_mut() {
+        let in_cycle = cycle_members.contains(&node.id);
+        for flag in flags_for(&node.metrics, in_cycle, thresholds) {
+            node.add_flag(flag);
         }
     }
 }
 
+/// Computes analysis flags from precomputed metrics and cycles.
+///
+/// Assigns the same flags as [`assign_flags`] without requiring a
+/// [`DependencyGraph`], for use with [`compute_metrics`] results.
+///
+/// [`compute_metrics`]: super::compute_metrics
+pub fn compute_flags(
+    metrics: &IndexMap<String, NodeMetrics>,
+    cycles: &[Vec<String>],
+    thresholds: &FlagThresholds,
+) -> IndexMap<String, Vec<NodeFlag>> {
+    let cycle_members: HashSet<&String> = cycles.iter().flatten().collect();
+
+    metrics
+        .iter()
+        .map(|(id, metrics)| {
+            let in_cycle = cycle_members.contains(id);
+            (id.clone(), flags_for(metrics, in_cycle, thresholds))
+        })
+        .collect()
+}
+
+/// Returns the flags implied by a node's metrics and cycle membership.
+fn flags_for(metrics: &NodeMetrics, in_cycle: bool, thresholds: &FlagThresholds) -> Vec<NodeFlag> {
+    let mut flags = Vec::new();
+
+    // Leaf: no outgoing dependencies
+    if metrics.fan_out == 0 {
+        flags.push(NodeFlag::Leaf);
+    }
+
+    // High fan-in
+    if metrics.fan_in >= thresholds.high_fan_in {
+        flags.push(NodeFlag::HighFanIn);
+    }
+
+    // High fan-out
+    if metrics.fan_out >= thresholds.high_fan_out {
+        flags.push(NodeFlag::HighFanOut);
+    }
+
+    // In cycle
+    if in_cycle {
+        flags.push(NodeFlag::InCycle);
+    }
+
+    flags
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,88 @@
+//! Graph abstraction for analyzer passes.
+//!
+//! This module defines the minimal view of a dependency graph that the
+//! cycle, metric, and flag passes need, so tools analyzing other
+//! stylesheet languages can reuse them with their own graph types.
+
+use petgraph::graph::DiGraph;
+
+use crate::graph::{DependencyGraph, NodeId};
+
+/// A directed dependency graph the analyzer passes can run on.
+///
+/// Edges point from a file to the files it depends on.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::DiGraph;
+/// use sass_dep::analyzer::{Analyzer, DependencyGraphLike};
+/// use sass_dep::graph::NodeId;
+///
+/// struct LessGraph {
+///     graph: DiGraph<String, ()>,
+///     entry: NodeId,
+/// }
+///
+/// impl DependencyGraphLike for LessGraph {
+///     type Node = String;
+///     type Edge = ();
+///
+///     fn graph(&self) -> &DiGraph<String, ()> {
+///         &self.graph
+///     }
+///
+///     fn node_id(&self, idx: NodeId) -> &str {
+///         &self.graph[idx]
+///     }
+///
+///     fn entry_points(&self) -> Vec<NodeId> {
+///         vec![self.entry]
+///     }
+/// }
+///
+/// let mut graph = DiGraph::new();
+/// let main = graph.add_node("main.less".to_string());
+/// let vars = graph.add_node("vars.less".to_string());
+/// graph.add_edge(main, vars, ());
+///
+/// let result = Analyzer::default().analyze_graph(&LessGraph { graph, entry: main });
+/// assert_eq!(result.metrics["vars.less"].depth, 1);
+/// ```
+pub trait DependencyGraphLike {
+    /// Node weight type.
+    type Node;
+    /// Edge weight type.
+    type Edge;
+
+    /// Returns the underlying directed graph.
+    fn graph(&self) -> &DiGraph<Self::Node, Self::Edge>;
+
+    /// Returns the unique ID (e.g. relative path) of a node.
+    fn node_id(&self, idx: NodeId) -> &str;
+
+    /// Returns the entry point nodes, from which depth is measured.
+    fn entry_points(&self) -> Vec<NodeId>;
+}
+
+impl DependencyGraphLike for DependencyGraph {
+    type Node = crate::graph::FileNode;
+    type Edge = crate::graph::DependencyEdge;
+
+    fn graph(&self) -> &DiGraph<Self::Node, Self::Edge> {
+        self.inner()
+    }
+
+    fn node_id(&self, idx: NodeId) -> &str {
+        &self.inner()[idx].id
+    }
+
+    fn entry_points(&self) -> Vec<NodeId> {
+        let mut entries: Vec<&String> = DependencyGraph::entry_points(self).iter().collect();
+        entries.sort();
+        entries
+            .into_iter()
+            .filter_map(|id| self.node_index().get(id).copied())
+            .collect()
+    }
+}
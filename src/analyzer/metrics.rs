@@ -3,36 +3,23 @@
 //! This module provides functions for calculating various metrics
 //! on the dependency graph nodes.
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
+use indexmap::IndexMap;
 use petgraph::Direction;
 
-use crate::graph::DependencyGraph;
+use super::DependencyGraphLike;
+use crate::graph::{DependencyGraph, NodeId, NodeMetrics};
 
 /// Calculates fan-in and fan-out for all nodes.
 ///
 /// - Fan-in: Number of files that depend on this file (in-degree)
 /// - Fan-out: Number of files this file depends on (out-degree)
 pub fn calculate_fan_in_out(graph: &mut DependencyGraph) {
-    let inner = graph.inner();
-    let node_index = graph.node_index().clone();
-
-    // Calculate metrics for each node
-    let metrics: Vec<(String, usize, usize)> = node_index
-        .iter()
-        .map(|(id, &idx)| {
-            let fan_in = inner.neighbors_directed(idx, Direction::Incoming).count();
-            let fan_out = inner.neighbors_directed(idx, Direction::Outgoing).count();
-            (id.clone(), fan_in, fan_out)
-        })
-        .collect();
-
-    // Apply metrics
-    for (id, fan_in, fan_out) in metrics {
-        if let Some(node) = graph.get_node_mut(&id) {
-            node.metrics.fan_in = fan_in;
-            node.metrics.fan_out = fan_out;
-        }
+    for (idx, fan_in, fan_out) in fan_in_out(graph) {
+        let metrics = &mut graph.inner_mut()[idx].metrics;
+        metrics.fan_in = fan_in;
+        metrics.fan_out = fan_out;
     }
 }
 
@@ -41,76 +28,139 @@ pub fn calculate_fan_in_out(graph: &mut DependencyGraph) {
 /// Depth is the shortest distance from any entry point to a node.
 /// Entry points have depth 0.
 pub fn calculate_depths(graph: &mut DependencyGraph) {
-    let entry_points: Vec<String> = graph.entry_points().iter().cloned().collect();
-    let node_index = graph.node_index().clone();
-
-    // Initialize all depths to max (unreachable)
-    let max_depth = usize::MAX;
-    for (id, _) in node_index.iter() {
-        if let Some(node) = graph.get_node_mut(id) {
-            node.metrics.depth = max_depth;
-        }
+    for (idx, depth) in depths(graph) {
+        graph.inner_mut()[idx].metrics.depth = depth;
     }
+}
 
-    // BFS from each entry point
-    let mut queue = VecDeque::new();
+/// Calculates transitive dependencies for all nodes.
+///
+/// Transitive dependencies are all files that a node depends on,
+/// directly or indirectly.
+pub fn calculate_transitive_deps(graph: &mut DependencyGraph) {
+    for (idx, count) in transitive_deps(graph) {
+        graph.inner_mut()[idx].metrics.transitive_deps = count;
+    }
+}
 
-    // Set entry points to depth 0 and add to queue
-    for entry_id in &entry_points {
-        if let Some(node) = graph.get_node_mut(entry_id) {
-            node.metrics.depth = 0;
+/// Computes all node metrics without modifying the graph.
+///
+/// Returns a map from node ID to metrics, in node index order. This is
+/// the entry point for graphs other than [`DependencyGraph`]. If several
+/// nodes share an ID, the map keeps the last one's metrics.
+pub fn compute_metrics<G: DependencyGraphLike>(graph: &G) -> IndexMap<String, NodeMetrics> {
+    let inner = graph.graph();
+    let mut metrics = vec![NodeMetrics::default(); inner.node_count()];
+
+    for (idx, fan_in, fan_out) in fan_in_out(graph) {
+        let node = &mut metrics[idx.index()];
+        node.fan_in = fan_in;
+        node.fan_out = fan_out;
+    }
+    for (idx, depth) in depths(graph) {
+        metrics[idx.index()].depth = depth;
+    }
+    for (idx, count) in transitive_deps(graph) {
+        metrics[idx.index()].transitive_deps = count;
+    }
+
+    inner
+        .node_indices()
+        .zip(metrics)
+        .map(|(idx, node)| (graph.node_id(idx).to_string(), node))
+        .collect()
+}
+
+/// Calculates which entry points reach each node.
+///
+/// Returns a map from file ID to the IDs of the entry points whose
+/// dependency subgraph includes the file, sorted by ID. Entry points
+/// reach themselves; unreachable files map to an empty list. If several
+/// nodes share an ID, the map keeps the last one's entry points.
+pub fn calculate_entry_reachability<G: DependencyGraphLike>(
+    graph: &G,
+) -> IndexMap<String, Vec<String>> {
+    let inner = graph.graph();
+
+    let mut entry_points = graph.entry_points();
+    entry_points.sort_by(|a, b| graph.node_id(*a).cmp(graph.node_id(*b)));
+
+    let mut reached_by: Vec<Vec<String>> = vec![Vec::new(); inner.node_count()];
+
+    for start in entry_points {
+        let entry_id = graph.node_id(start).to_string();
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(current) = stack.pop() {
+            if visited.insert(current) {
+                stack.extend(inner.neighbors_directed(current, Direction::Outgoing));
+            }
         }
-        if let Some(&idx) = node_index.get(entry_id) {
-            queue.push_back((idx, 0usize));
+
+        for idx in visited {
+            reached_by[idx.index()].push(entry_id.clone());
         }
     }
 
-    // BFS traversal
-    let inner = graph.inner();
-    let mut visited_at_depth: std::collections::HashMap<petgraph::graph::NodeIndex, usize> =
-        std::collections::HashMap::new();
+    inner
+        .node_indices()
+        .zip(reached_by)
+        .map(|(idx, entries)| (graph.node_id(idx).to_string(), entries))
+        .collect()
+}
 
-    for entry_id in &entry_points {
-        if let Some(&idx) = node_index.get(entry_id) {
-            visited_at_depth.insert(idx, 0);
-        }
+/// Returns fan-in and fan-out for every node.
+fn fan_in_out<G: DependencyGraphLike>(graph: &G) -> Vec<(NodeId, usize, usize)> {
+    let inner = graph.graph();
+    inner
+        .node_indices()
+        .map(|idx| {
+            let fan_in = inner.neighbors_directed(idx, Direction::Incoming).count();
+            let fan_out = inner.neighbors_directed(idx, Direction::Outgoing).count();
+            (idx, fan_in, fan_out)
+        })
+        .collect()
+}
+
+/// Returns the BFS depth of every node from the nearest entry point.
+///
+/// Nodes unreachable from any entry point get `usize::MAX`.
+fn depths<G: DependencyGraphLike>(graph: &G) -> Vec<(NodeId, usize)> {
+    let inner = graph.graph();
+    let mut depth: HashMap<NodeId, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    // Entry points start at depth 0
+    for idx in graph.entry_points() {
+        depth.insert(idx, 0);
+        queue.push_back((idx, 0usize));
     }
 
-    while let Some((idx, depth)) = queue.pop_front() {
-        let next_depth = depth + 1;
+    // BFS traversal
+    while let Some((idx, current)) = queue.pop_front() {
+        let next_depth = current + 1;
 
         for neighbor in inner.neighbors_directed(idx, Direction::Outgoing) {
-            let current_depth = visited_at_depth.get(&neighbor).copied().unwrap_or(max_depth);
-
-            if next_depth < current_depth {
-                visited_at_depth.insert(neighbor, next_depth);
+            if next_depth < depth.get(&neighbor).copied().unwrap_or(usize::MAX) {
+                depth.insert(neighbor, next_depth);
                 queue.push_back((neighbor, next_depth));
             }
         }
     }
 
-    // Apply depths to nodes
-    for (id, &idx) in node_index.iter() {
-        if let Some(&depth) = visited_at_depth.get(&idx) {
-            if let Some(node) = graph.get_node_mut(id) {
-                node.metrics.depth = depth;
-            }
-        }
-    }
+    inner
+        .node_indices()
+        .map(|idx| (idx, depth.get(&idx).copied().unwrap_or(usize::MAX)))
+        .collect()
 }
 
-/// Calculates transitive dependencies for all nodes.
-///
-/// Transitive dependencies are all files that a node depends on,
-/// directly or indirectly.
-pub fn calculate_transitive_deps(graph: &mut DependencyGraph) {
-    let node_index = graph.node_index().clone();
-    let inner = graph.inner();
-
-    // Calculate transitive deps for each node
-    let transitive: Vec<(String, usize)> = node_index
-        .iter()
-        .map(|(id, &idx)| {
+/// Returns the number of transitive dependencies of every node.
+fn transitive_deps<G: DependencyGraphLike>(graph: &G) -> Vec<(NodeId, usize)> {
+    let inner = graph.graph();
+    inner
+        .node_indices()
+        .map(|idx| {
             let mut visited = HashSet::new();
             let mut stack = vec![idx];
 
@@ -122,16 +172,9 @@ pub fn calculate_transitive_deps(graph: &mut DependencyGraph) {
                 }
             }
 
-            (id.clone(), visited.len())
+            (idx, visited.len())
         })
-        .collect();
-
-    // Apply metrics
-    for (id, count) in transitive {
-        if let Some(node) = graph.get_node_mut(&id) {
-            node.metrics.transitive_deps = count;
-        }
-    }
+        .collect()
 }
 
 #[cfg(test)]
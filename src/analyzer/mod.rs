@@ -6,8 +6,14 @@
 //! - Cycle detection using Tarjan's algorithm
 //! - Depth calculation via BFS from entry points
 //! - Fan-in/fan-out computation
+//! - Redundant and dead entry point detection
+//! - Namespace shadowing detection
+//! - Duplicate basename detection
 //! - Flag assignment based on configurable thresholds
 //!
+//! The cycle, metric, and flag passes also run on external graphs through
+//! the [`DependencyGraphLike`] trait and [`Analyzer::analyze_graph`].
+//!
 //! # Example
 //!
 //! ```no_run
@@ -22,12 +28,41 @@
 //! ```
 
 mod cycles;
+mod duplicates;
+mod entries;
 mod flags;
+mod graph_like;
 mod metrics;
+mod shadowing;
+
+pub use cycles::{detect_cycles, mark_cycle_edges};
+pub use duplicates::{detect_duplicate_basenames, DuplicateBasename, DuplicateFile};
+pub use entries::{detect_dead_entries, detect_redundant_entries, RedundantEntry};
+pub use flags::{assign_flags, compute_flags, FlagThresholds};
+pub use graph_like::DependencyGraphLike;
+pub use metrics::{
+    calculate_depths, calculate_entry_reachability, calculate_fan_in_out,
+    calculate_transitive_deps, compute_metrics,
+};
+pub use shadowing::{detect_namespace_shadowing, NamespaceShadowing, ShadowingKind};
+
+use indexmap::IndexMap;
 
-pub use cycles::detect_cycles;
-pub use flags::{assign_flags, FlagThresholds};
-pub use metrics::{calculate_depths, calculate_fan_in_out, calculate_transitive_deps};
+use crate::graph::{NodeFlag, NodeMetrics};
+
+/// Results of analyzing a [`DependencyGraphLike`] graph.
+///
+/// Returned by [`Analyzer::analyze_graph`], which leaves the graph
+/// untouched.
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisResult {
+    /// Detected cycles, as lists of node IDs.
+    pub cycles: Vec<Vec<String>>,
+    /// Metrics of every node, keyed by node ID.
+    pub metrics: IndexMap<String, NodeMetrics>,
+    /// Analysis flags of every node, keyed by node ID.
+    pub flags: IndexMap<String, Vec<NodeFlag>>,
+}
 
 /// Configuration for the analyzer.
 #[derive(Debug, Clone, Default)]
@@ -58,10 +93,14 @@ impl Analyzer {
     /// 3. Calculates depth from entry points
     /// 4. Calculates transitive dependencies
     /// 5. Assigns flags based on thresholds
+    /// 6. Detects entry points reachable from other entry points
+    /// 7. Detects files sharing a module name across directories
+    /// 8. Detects entry points that likely compile to empty output
     pub fn analyze(&self, graph: &mut crate::graph::DependencyGraph) {
         // Step 1: Detect cycles
         let cycles = detect_cycles(graph);
         graph.set_cycles(cycles);
+        mark_cycle_edges(graph);
 
         // Step 2: Calculate fan-in/fan-out
         calculate_fan_in_out(graph);
@@ -74,6 +113,38 @@ impl Analyzer {
 
         // Step 5: Assign flags
         assign_flags(graph, &self.config.thresholds);
+
+        // Step 6: Detect redundant entry points
+        let redundant = detect_redundant_entries(graph);
+        graph.set_redundant_entries(redundant);
+
+        // Step 7: Detect duplicate basenames
+        let duplicates = detect_duplicate_basenames(graph);
+        graph.set_duplicate_basenames(duplicates);
+
+        // Step 8: Detect dead entry points
+        let dead = detect_dead_entries(graph);
+        graph.set_dead_entries(dead);
+    }
+}
+
+impl Analyzer {
+    /// Runs the cycle, metric, and flag passes on any graph.
+    ///
+    /// Use this to reuse the analysis for graphs other than
+    /// [`DependencyGraph`](crate::graph::DependencyGraph), e.g. from a
+    /// LESS or CSS modules analyzer. Sass-specific checks (redundant,
+    /// dead, and duplicate entries) are not run.
+    pub fn analyze_graph<G: DependencyGraphLike>(&self, graph: &G) -> AnalysisResult {
+        let cycles = detect_cycles(graph);
+        let metrics = compute_metrics(graph);
+        let flags = compute_flags(&metrics, &cycles, &self.config.thresholds);
+
+        AnalysisResult {
+            cycles,
+            metrics,
+            flags,
+        }
     }
 }
 
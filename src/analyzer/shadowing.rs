@@ -0,0 +1,200 @@
+//! Namespace shadowing detection.
+//!
+//! This module flags `@use` namespaces that collide with Sass built-in
+//! module names or CSS keywords. Such namespaces make member access like
+//! `math.div()` resolve to an unexpected module and are a common source
+//! of confusing errors.
+
+use crate::parser::{Directive, Location, Namespace};
+
+/// Sass built-in module names (`sass:<name>`).
+const BUILTIN_MODULES: [&str; 7] = [
+    "color", "list", "map", "math", "meta", "selector", "string",
+];
+
+/// CSS-wide keywords and special functions that are easily confused with
+/// namespaced member access.
+const CSS_KEYWORDS: [&str; 13] = [
+    "inherit",
+    "initial",
+    "unset",
+    "revert",
+    "revert-layer",
+    "calc",
+    "clamp",
+    "min",
+    "max",
+    "url",
+    "var",
+    "env",
+    "element",
+];
+
+/// Kind of namespace collision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShadowingKind {
+    /// The namespace matches a Sass built-in module name.
+    BuiltinModule {
+        /// Whether the same file also loads that built-in module.
+        builtin_loaded: bool,
+    },
+    /// The namespace matches a CSS keyword or special function.
+    CssKeyword,
+}
+
+/// A `@use` namespace that shadows a built-in module or CSS keyword.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamespaceShadowing {
+    /// File ID containing the `@use` directive.
+    pub file: String,
+    /// The effective namespace.
+    pub namespace: String,
+    /// The path from the `@use` directive.
+    pub target: String,
+    /// Source location of the directive.
+    pub location: Location,
+    /// Kind of collision.
+    pub kind: ShadowingKind,
+}
+
+impl std::fmt::Display for NamespaceShadowing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: namespace '{}' for '{}' ",
+            self.file, self.location.line, self.location.column, self.namespace, self.target
+        )?;
+        match self.kind {
+            ShadowingKind::BuiltinModule { builtin_loaded: true } => {
+                write!(f, "collides with the loaded built-in module 'sass:{}'", self.namespace)
+            }
+            ShadowingKind::BuiltinModule { builtin_loaded: false } => {
+                write!(f, "shadows the built-in module 'sass:{}'", self.namespace)
+            }
+            ShadowingKind::CssKeyword => write!(f, "shadows the CSS keyword '{}'", self.namespace),
+        }
+    }
+}
+
+/// Detects `@use` namespaces in a file that shadow built-ins or CSS keywords.
+///
+/// # Arguments
+///
+/// * `file` - File ID the directives were parsed from
+/// * `directives` - The parsed directives of the file
+///
+/// # Returns
+///
+/// A vector of findings in source order.
+pub fn detect_namespace_shadowing(
+    file: &str,
+    directives: &[Directive],
+) -> Vec<NamespaceShadowing> {
+    let uses: Vec<_> = directives
+        .iter()
+        .filter_map(|d| match d {
+            Directive::Use(u) => Some(u),
+            _ => None,
+        })
+        .collect();
+
+    let loaded_builtins: Vec<&str> = uses
+        .iter()
+        .filter_map(|u| u.path.strip_prefix("sass:"))
+        .collect();
+
+    let mut findings = Vec::new();
+    for u in uses {
+        let Some(namespace) = effective_namespace(&u.path, u.namespace.as_ref()) else {
+            continue;
+        };
+        let is_builtin = u.path.starts_with("sass:");
+
+        let kind = if !is_builtin && BUILTIN_MODULES.contains(&namespace.as_str()) {
+            ShadowingKind::BuiltinModule {
+                builtin_loaded: loaded_builtins.contains(&namespace.as_str()),
+            }
+        } else if CSS_KEYWORDS.contains(&namespace.as_str()) {
+            ShadowingKind::CssKeyword
+        } else {
+            continue;
+        };
+
+        findings.push(NamespaceShadowing {
+            file: file.to_string(),
+            namespace,
+            target: u.path.clone(),
+            location: u.location.clone(),
+            kind,
+        });
+    }
+
+    findings
+}
+
+/// Returns the namespace a `@use` directive binds, or `None` for `as *`.
+///
+/// Without an `as` clause, Sass derives the namespace from the last path
+/// component, without leading underscore or extension.
+fn effective_namespace(path: &str, namespace: Option<&Namespace>) -> Option<String> {
+    match namespace {
+        Some(Namespace::Named(n)) => Some(n.clone()),
+        Some(Namespace::Star) => None,
+        Some(Namespace::Default) | None => {
+            let path = path.strip_prefix("sass:").unwrap_or(path);
+            let name = path.rsplit('/').next().unwrap_or(path);
+            let name = name.trim_start_matches('_');
+            let name = name.split('.').next().unwrap_or(name);
+            Some(name.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    #[test]
+    fn namespace_collides_with_loaded_builtin() {
+        let directives = Parser::parse("@use \"sass:math\";\n@use \"utils\" as math;\n").unwrap();
+        let findings = detect_namespace_shadowing("main.scss", &directives);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].namespace, "math");
+        assert_eq!(findings[0].target, "utils");
+        assert_eq!(
+            findings[0].kind,
+            ShadowingKind::BuiltinModule { builtin_loaded: true }
+        );
+    }
+
+    #[test]
+    fn default_namespace_shadows_builtin() {
+        let directives = Parser::parse("@use \"helpers/_map.scss\";\n").unwrap();
+        let findings = detect_namespace_shadowing("main.scss", &directives);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].namespace, "map");
+        assert_eq!(
+            findings[0].kind,
+            ShadowingKind::BuiltinModule { builtin_loaded: false }
+        );
+    }
+
+    #[test]
+    fn namespace_shadows_css_keyword() {
+        let directives = Parser::parse("@use \"sass:math\" as calc;\n").unwrap();
+        let findings = detect_namespace_shadowing("main.scss", &directives);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, ShadowingKind::CssKeyword);
+    }
+
+    #[test]
+    fn regular_namespaces_not_flagged() {
+        let input = "@use \"sass:math\";\n@use \"variables\" as vars;\n@use \"mixins\" as *;\n";
+        let directives = Parser::parse(input).unwrap();
+        assert!(detect_namespace_shadowing("main.scss", &directives).is_empty());
+    }
+}
@@ -4,8 +4,11 @@
 //! for the sass-dep tool.
 
 use clap::{Parser, Subcommand, ValueEnum};
+use globset::{Glob, GlobBuilder};
 use std::path::PathBuf;
 
+use crate::graph::LabelStrategy;
+
 /// SCSS dependency graph analyzer.
 ///
 /// Analyzes SCSS codebases to build file-level dependency graphs,
@@ -39,6 +42,13 @@ pub struct Cli {
     #[arg(long = "load-path", short = 'I', global = true)]
     pub load_paths: Vec<PathBuf>,
 
+    /// Add conventional load paths found under the root.
+    ///
+    /// Adds `node_modules`, `styles`, and `src/styles` as load
+    /// paths when they exist, after any explicit `--load-path`.
+    #[arg(long, global = true)]
+    pub auto_load_paths: bool,
+
     /// Suppress non-error output.
     ///
     /// When enabled, only error messages will be printed.
@@ -91,6 +101,22 @@ pub enum Commands {
         #[arg(long)]
         include_orphans: bool,
 
+        /// Do not respect .gitignore files.
+        ///
+        /// By default, orphan discovery skips paths ignored by
+        /// `.gitignore`, `.ignore`, and git exclude files.
+        #[arg(long)]
+        no_gitignore: bool,
+
+        /// Node label strategy for rendered output.
+        ///
+        /// One of `full`, `basename`, `parent`, or a template
+        /// such as `'{basename} ({fan_in})'`. Placeholders:
+        /// `{path}`, `{basename}`, `{parent}`, `{fan_in}`,
+        /// `{fan_out}`, `{depth}`, `{transitive_deps}`.
+        #[arg(long, default_value = "basename", value_name = "STRATEGY")]
+        label: LabelStrategy,
+
         /// Open interactive web visualization.
         ///
         /// Starts a local HTTP server and opens the browser
@@ -145,6 +171,23 @@ pub enum Commands {
         /// than this limit.
         #[arg(long)]
         max_fan_in: Option<usize>,
+
+        /// Maximum entry points reaching matching files (can be repeated).
+        ///
+        /// Takes `<GLOB>=<N>`. Exit with error if a file matching
+        /// the glob is included by more than N entry point
+        /// subgraphs, e.g. `--max-entry-reach 'admin/**=1'`.
+        #[arg(long, value_name = "GLOB=N", value_parser = parse_entry_reach_rule)]
+        max_entry_reach: Vec<EntryReachRule>,
+
+        /// Only check files affected by working tree changes.
+        ///
+        /// Uses git to find changed and untracked files, then limits
+        /// rules to those files, the files that transitively depend on
+        /// them, and everything below the files they import now or
+        /// imported at HEAD.
+        #[arg(long)]
+        changed_only: bool,
     },
 
     /// Export graph to visualization formats.
@@ -163,6 +206,131 @@ pub enum Commands {
         #[arg(long, default_value = "dot", value_enum)]
         format: ExportFormat,
     },
+
+    /// Safely delete a file.
+    ///
+    /// Scans every SCSS file under the root and refuses to
+    /// delete a file that is still loaded by others, listing
+    /// the directives that depend on it.
+    Rm {
+        /// File to delete.
+        file: PathBuf,
+
+        /// Report what would happen without changing anything.
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Delete the file even if other files depend on it.
+        #[arg(long)]
+        force: bool,
+
+        /// Also remove the directives that load the file.
+        ///
+        /// If any directive cannot be removed automatically, such as
+        /// a multi-path `@import`, nothing is edited or deleted.
+        #[arg(long, requires = "force")]
+        fix: bool,
+
+        /// Do not respect .gitignore files.
+        #[arg(long)]
+        no_gitignore: bool,
+    },
+
+    /// Serve several analyses from one web server.
+    ///
+    /// Hosts previously generated JSON analyses side by side,
+    /// so one long-running server can back dashboards for
+    /// several projects.
+    Serve {
+        /// Analysis files to serve, optionally named.
+        ///
+        /// Takes `[NAME=]FILE`. Without a name, the file stem
+        /// is used, e.g. `app=dist/app.json` or `admin.json`.
+        /// Names may only contain letters, digits, `_`, `.` and `-`.
+        #[arg(required = true, value_name = "[NAME=]FILE", value_parser = parse_project_input)]
+        projects: Vec<ProjectInput>,
+
+        /// Port for web server (default: 3000).
+        #[arg(long, default_value = "3000")]
+        port: u16,
+    },
+
+    /// Measure parse, build, and analyze throughput.
+    ///
+    /// Generates a synthetic SCSS project in a temporary
+    /// directory and times each stage, reporting the best
+    /// of several runs.
+    Bench {
+        /// Number of partials to generate.
+        #[arg(long, default_value = "1000")]
+        files: usize,
+
+        /// Dependencies per partial.
+        #[arg(long, default_value = "4")]
+        fan_out: usize,
+
+        /// Number of timed runs per stage.
+        #[arg(long, default_value = "5")]
+        iterations: usize,
+    },
+}
+
+/// Limit on how many entry points may reach files matching a glob.
+#[derive(Debug, Clone)]
+pub struct EntryReachRule {
+    /// Glob matched against file IDs (relative paths).
+    pub glob: Glob,
+    /// Maximum number of entry points that may reach a matching file.
+    pub max: usize,
+}
+
+/// Parses an `<GLOB>=<N>` entry reach rule.
+fn parse_entry_reach_rule(value: &str) -> Result<EntryReachRule, String> {
+    let (pattern, max) = value
+        .rsplit_once('=')
+        .ok_or_else(|| format!("expected <GLOB>=<N>, got '{}'", value))?;
+    let glob = GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let max = max
+        .parse()
+        .map_err(|_| format!("invalid maximum '{}' in '{}'", max, value))?;
+    Ok(EntryReachRule { glob, max })
+}
+
+/// A named analysis file served by the `serve` command.
+#[derive(Debug, Clone)]
+pub struct ProjectInput {
+    /// Project name, used in API paths.
+    pub name: String,
+    /// Path to the JSON analysis file.
+    pub path: PathBuf,
+}
+
+/// Parses a `[NAME=]FILE` project input.
+fn parse_project_input(value: &str) -> Result<ProjectInput, String> {
+    let (name, path) = match value.split_once('=') {
+        Some((name, path)) => (name.to_string(), PathBuf::from(path)),
+        None => {
+            let path = PathBuf::from(value);
+            let name = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            (name, path)
+        }
+    };
+
+    // Names appear in URLs, so keep them to unreserved characters
+    let valid = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-');
+    if name.is_empty() || name == "." || name == ".." || !name.chars().all(valid) {
+        return Err(format!(
+            "invalid project name in '{}' (use letters, digits, '_', '.' or '-')",
+            value
+        ));
+    }
+    Ok(ProjectInput { name, path })
 }
 
 /// Output formats for the analyze command.
@@ -173,6 +341,12 @@ pub enum OutputFormat {
     /// Outputs the full analysis as a JSON document
     /// conforming to the sass-dep schema.
     Json,
+
+    /// SVG image.
+    ///
+    /// Renders the graph using the built-in layout,
+    /// with nodes colored by flag. No Graphviz needed.
+    Svg,
 }
 
 /// Export formats for graph visualization.
@@ -205,4 +379,32 @@ mod tests {
         use clap::CommandFactory;
         Cli::command().debug_assert();
     }
+
+    #[test]
+    fn entry_reach_rule_parsing() {
+        let rule = parse_entry_reach_rule("admin/**=1").unwrap();
+        assert_eq!(rule.glob.glob(), "admin/**");
+        assert_eq!(rule.max, 1);
+
+        assert!(parse_entry_reach_rule("admin/**").is_err());
+        assert!(parse_entry_reach_rule("admin/**=x").is_err());
+    }
+
+    #[test]
+    fn project_input_parsing() {
+        let input = parse_project_input("app=dist/analysis.json").unwrap();
+        assert_eq!(input.name, "app");
+        assert_eq!(input.path, PathBuf::from("dist/analysis.json"));
+
+        let input = parse_project_input("reports/admin.json").unwrap();
+        assert_eq!(input.name, "admin");
+
+        assert!(parse_project_input("=admin.json").is_err());
+        assert!(parse_project_input("a/b=admin.json").is_err());
+        assert!(parse_project_input("my app=admin.json").is_err());
+        assert!(parse_project_input("a?b=admin.json").is_err());
+        assert!(parse_project_input("..=admin.json").is_err());
+        assert!(parse_project_input("reports/my report.json").is_err());
+        assert!(parse_project_input("v1.2_admin-old=admin.json").is_ok());
+    }
 }
@@ -5,4 +5,4 @@
 
 mod commands;
 
-pub use commands::{Cli, Commands, ExportFormat, OutputFormat};
+pub use commands::{Cli, Commands, EntryReachRule, ExportFormat, OutputFormat, ProjectInput};
@@ -5,14 +5,21 @@
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use serde::Serialize;
 
-use crate::analyzer::Analyzer;
-use crate::cli::{ExportFormat, OutputFormat};
-use crate::graph::DependencyGraph;
+use crate::analyzer::{calculate_entry_reachability, Analyzer};
+use crate::cli::{EntryReachRule, ExportFormat, OutputFormat, ProjectInput};
+use crate::graph::{DependencyGraph, LabelStrategy};
+use crate::layout::{compute_layout, render_svg, LayoutConfig};
 use crate::output::{OutputSchema, Serializer};
+use crate::parser::{Directive, ParseError, Parser};
 use crate::resolver::{Resolver, ResolverConfig};
+use crate::synthetic::{generate, SyntheticConfig, SyntheticProject};
 
 /// Violation found during check command.
 #[derive(Debug, Clone)]
@@ -25,6 +32,65 @@ pub enum Violation {
     MaxFanOut { file: String, fan_out: usize, max: usize },
     /// File exceeds maximum fan-in.
     MaxFanIn { file: String, fan_in: usize, max: usize },
+    /// File is reached by too many entry points.
+    MaxEntryReach {
+        file: String,
+        pattern: String,
+        entries: Vec<String>,
+        max: usize,
+    },
+}
+
+/// Structured error written to stdout when a JSON run fails.
+///
+/// Lets wrappers always parse the output, even when analysis
+/// could not complete.
+#[derive(Debug, Serialize)]
+pub struct ErrorOutput {
+    pub error: ErrorDetails,
+}
+
+/// Details of a failed run.
+#[derive(Debug, Serialize)]
+pub struct ErrorDetails {
+    /// Machine-readable error code.
+    pub code: &'static str,
+    /// Top-level error message.
+    pub message: String,
+    /// Underlying causes, outermost first.
+    pub details: Vec<String>,
+}
+
+impl ErrorOutput {
+    /// Builds a structured error from an error and its causes.
+    pub fn from_error(err: &anyhow::Error) -> Self {
+        let code = err
+            .chain()
+            .find_map(|cause| {
+                if cause.downcast_ref::<ParseError>().is_some() {
+                    Some("parse_error")
+                } else {
+                    cause.downcast_ref::<io::Error>().map(|e| match e.kind() {
+                        io::ErrorKind::NotFound => "file_not_found",
+                        _ => "io_error",
+                    })
+                }
+            })
+            .unwrap_or("analysis_failed");
+
+        Self {
+            error: ErrorDetails {
+                code,
+                message: err.to_string(),
+                details: err.chain().skip(1).map(|c| c.to_string()).collect(),
+            },
+        }
+    }
+
+    /// Serializes the error to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize error")
+    }
 }
 
 /// Options for the analyze command.
@@ -32,16 +98,51 @@ pub enum Violation {
 pub struct AnalyzeOptions<'a> {
     pub root: &'a Path,
     pub load_paths: &'a [PathBuf],
+    pub auto_load_paths: bool,
     pub entry_points: &'a [PathBuf],
     pub output: Option<&'a Path>,
     pub format: OutputFormat,
     pub include_orphans: bool,
+    pub respect_gitignore: bool,
+    pub label: LabelStrategy,
     pub quiet: bool,
     pub verbose: u8,
     pub web: bool,
     pub port: u16,
 }
 
+/// Options for the check command.
+#[derive(Debug)]
+pub struct CheckOptions<'a> {
+    pub root: &'a Path,
+    pub load_paths: &'a [PathBuf],
+    pub auto_load_paths: bool,
+    pub entry_points: &'a [PathBuf],
+    pub no_cycles: bool,
+    pub max_depth: Option<usize>,
+    pub max_fan_out: Option<usize>,
+    pub max_fan_in: Option<usize>,
+    pub max_entry_reach: &'a [EntryReachRule],
+    pub changed_only: bool,
+    pub quiet: bool,
+    pub verbose: u8,
+}
+
+/// Options for the rm command.
+#[derive(Debug)]
+pub struct RmOptions<'a> {
+    pub root: &'a Path,
+    pub load_paths: &'a [PathBuf],
+    pub auto_load_paths: bool,
+    pub file: &'a Path,
+    pub dry_run: bool,
+    pub force: bool,
+    pub fix: bool,
+    pub respect_gitignore: bool,
+    pub quiet: bool,
+    pub verbose: u8,
+}
+
 /// Execute the analyze command.
 ///
 /// Builds a dependency graph from the entry points and outputs
@@ -55,10 +156,13 @@ pub fn analyze(opts: AnalyzeOptions) -> Result<()> {
     }
 
     // Set up resolver
-    let config = ResolverConfig {
-        load_paths: opts.load_paths.to_vec(),
-        extensions: vec!["scss".to_string(), "sass".to_string()],
-    };
+    let config = resolver_config(
+        &root,
+        opts.load_paths,
+        opts.auto_load_paths,
+        opts.quiet,
+        opts.verbose,
+    );
     let resolver = Resolver::new(config);
 
     // Build graph
@@ -84,13 +188,29 @@ pub fn analyze(opts: AnalyzeOptions) -> Result<()> {
 
     // Include orphans if requested
     if opts.include_orphans {
-        graph.discover_orphans(&root, &resolver)?;
+        graph.discover_orphans(&root, &resolver, opts.respect_gitignore)?;
     }
 
     // Run analysis
     let analyzer = Analyzer::default();
     analyzer.analyze(&mut graph);
 
+    if !opts.quiet {
+        warn_redundant_entries(&graph);
+        warn_dead_entries(&graph);
+        warn_namespace_shadowing(&graph);
+        warn_duplicate_basenames(&graph);
+    }
+
+    // Compute node positions for rendering
+    let layout_config = LayoutConfig {
+        label: opts.label.clone(),
+        ..LayoutConfig::default()
+    };
+    if opts.format == OutputFormat::Svg {
+        compute_layout(&mut graph, &layout_config);
+    }
+
     // Generate output schema
     let schema = OutputSchema::from_graph(&graph, &root);
 
@@ -104,6 +224,7 @@ pub fn analyze(opts: AnalyzeOptions) -> Result<()> {
         // Generate output
         let output_content = match opts.format {
             OutputFormat::Json => Serializer::to_json(&schema)?,
+            OutputFormat::Svg => render_svg(&graph, &layout_config),
         };
 
         // Write output
@@ -128,33 +249,25 @@ pub fn analyze(opts: AnalyzeOptions) -> Result<()> {
 ///
 /// Analyzes the dependency graph and returns any constraint violations.
 ///
-/// # Arguments
-///
-/// * `root` - Project root directory
-/// * `load_paths` - Additional Sass load paths
-/// * `entry_points` - Entry point SCSS files
-/// * `no_cycles` - Fail if cycles are detected
-/// * `max_depth` - Maximum allowed depth
-/// * `max_fan_out` - Maximum allowed fan-out
-/// * `max_fan_in` - Maximum allowed fan-in
-/// * `quiet` - Suppress non-error output
-/// * `verbose` - Verbosity level
-///
 /// # Returns
 ///
 /// A vector of violations found. Empty if all constraints pass.
-#[allow(clippy::too_many_arguments)]
-pub fn check(
-    root: &Path,
-    load_paths: &[PathBuf],
-    entry_points: &[PathBuf],
-    no_cycles: bool,
-    max_depth: Option<usize>,
-    max_fan_out: Option<usize>,
-    max_fan_in: Option<usize>,
-    quiet: bool,
-    verbose: u8,
-) -> Result<Vec<Violation>> {
+pub fn check(opts: CheckOptions) -> Result<Vec<Violation>> {
+    let CheckOptions {
+        root,
+        load_paths,
+        auto_load_paths,
+        entry_points,
+        no_cycles,
+        max_depth,
+        max_fan_out,
+        max_fan_in,
+        max_entry_reach,
+        changed_only,
+        quiet,
+        verbose,
+    } = opts;
+
     let root = root.canonicalize().context("Failed to resolve root directory")?;
 
     if verbose > 0 && !quiet {
@@ -162,10 +275,7 @@ pub fn check(
     }
 
     // Set up resolver
-    let config = ResolverConfig {
-        load_paths: load_paths.to_vec(),
-        extensions: vec!["scss".to_string(), "sass".to_string()],
-    };
+    let config = resolver_config(&root, load_paths, auto_load_paths, quiet, verbose);
     let resolver = Resolver::new(config);
 
     // Build graph
@@ -189,12 +299,43 @@ pub fn check(
     let analyzer = Analyzer::default();
     analyzer.analyze(&mut graph);
 
+    if !quiet {
+        warn_redundant_entries(&graph);
+        warn_dead_entries(&graph);
+        warn_namespace_shadowing(&graph);
+        warn_duplicate_basenames(&graph);
+    }
+
+    // Restrict checks to files affected by working tree changes
+    let scope = if changed_only {
+        let changed = git_changed_files(&root)?;
+        let previous = git_previous_targets(&root, &changed, &resolver);
+        let scope = graph.affected_by(
+            changed.iter().map(|s| s.as_str()),
+            previous.iter().map(|s| s.as_str()),
+        );
+        if verbose > 0 && !quiet {
+            eprintln!(
+                "Checking {} file(s) affected by {} changed file(s)",
+                scope.len(),
+                changed.len()
+            );
+        }
+        Some(scope)
+    } else {
+        None
+    };
+    let in_scope = |id: &str| scope.as_ref().is_none_or(|s| s.contains(id));
+
     let mut violations = Vec::new();
 
     // Check for cycles
     if no_cycles {
         let cycles = graph.get_cycles();
         for cycle in cycles {
+            if !cycle.iter().any(|id| in_scope(id)) {
+                continue;
+            }
             if !quiet {
                 eprintln!(
                     "Cycle detected: {}",
@@ -212,7 +353,7 @@ pub fn check(
     // Check depth constraints
     if let Some(max) = max_depth {
         for (id, node) in graph.nodes() {
-            if node.metrics.depth > max {
+            if node.metrics.depth > max && in_scope(id) {
                 if !quiet {
                     eprintln!(
                         "Depth violation: {} has depth {} (max: {})",
@@ -231,7 +372,7 @@ pub fn check(
     // Check fan-out constraints
     if let Some(max) = max_fan_out {
         for (id, node) in graph.nodes() {
-            if node.metrics.fan_out > max {
+            if node.metrics.fan_out > max && in_scope(id) {
                 if !quiet {
                     eprintln!(
                         "Fan-out violation: {} has fan-out {} (max: {})",
@@ -250,7 +391,7 @@ pub fn check(
     // Check fan-in constraints
     if let Some(max) = max_fan_in {
         for (id, node) in graph.nodes() {
-            if node.metrics.fan_in > max {
+            if node.metrics.fan_in > max && in_scope(id) {
                 if !quiet {
                     eprintln!(
                         "Fan-in violation: {} has fan-in {} (max: {})",
@@ -266,6 +407,34 @@ pub fn check(
         }
     }
 
+    // Check entry reach constraints
+    if !max_entry_reach.is_empty() {
+        let reachability = calculate_entry_reachability(&graph);
+        for rule in max_entry_reach {
+            let matcher = rule.glob.compile_matcher();
+            for (id, entries) in &reachability {
+                if entries.len() > rule.max && matcher.is_match(id) && in_scope(id) {
+                    if !quiet {
+                        eprintln!(
+                            "Entry reach violation: {} is reached by {} entry points (max: {} for '{}'): {}",
+                            id,
+                            entries.len(),
+                            rule.max,
+                            rule.glob,
+                            entries.join(", ")
+                        );
+                    }
+                    violations.push(Violation::MaxEntryReach {
+                        file: id.clone(),
+                        pattern: rule.glob.to_string(),
+                        entries: entries.clone(),
+                        max: rule.max,
+                    });
+                }
+            }
+        }
+    }
+
     if violations.is_empty() && !quiet {
         eprintln!("All checks passed.");
     }
@@ -273,6 +442,163 @@ pub fn check(
     Ok(violations)
 }
 
+/// Lists files changed in the git working tree under `root`.
+///
+/// Includes staged and unstaged changes against `HEAD` plus untracked
+/// files that are not ignored. Paths are relative to `root`, matching
+/// graph file IDs.
+fn git_changed_files(root: &Path) -> Result<Vec<String>> {
+    let inside = Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .current_dir(root)
+        .output()
+        .context("Failed to run git")?;
+    if !inside.status.success() || inside.stdout.trim_ascii() != b"true" {
+        anyhow::bail!("--changed-only requires a git working tree");
+    }
+
+    let mut files = Vec::new();
+
+    // NUL-separated output keeps non-ASCII paths unquoted
+    for args in [
+        &["diff", "--name-only", "-z", "--relative", "HEAD"][..],
+        &["ls-files", "-z", "--others", "--exclude-standard"][..],
+    ] {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .context("Failed to run git")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!(
+                "git {} failed: {}",
+                args[0],
+                stderr.lines().next().unwrap_or_default()
+            );
+        }
+        files.extend(
+            output
+                .stdout
+                .split(|&b| b == 0)
+                .filter(|path| !path.is_empty())
+                .map(|path| String::from_utf8_lossy(path).into_owned()),
+        );
+    }
+
+    Ok(files)
+}
+
+/// Lists the files that the changed files imported at `HEAD`.
+///
+/// Files without a committed version, or whose committed version does not
+/// parse, are skipped. Targets that no longer resolve are not part of the
+/// graph and need no checking.
+fn git_previous_targets(root: &Path, changed: &[String], resolver: &Resolver) -> Vec<String> {
+    let mut targets = Vec::new();
+
+    for file in changed {
+        let output = Command::new("git")
+            .args(["show", &format!("HEAD:./{}", file)])
+            .current_dir(root)
+            .output();
+        let content = match output {
+            Ok(output) if output.status.success() => output.stdout,
+            _ => continue,
+        };
+        let Ok(directives) = Parser::parse(&String::from_utf8_lossy(&content)) else {
+            continue;
+        };
+
+        let path = root.join(file);
+        let Some(base_dir) = path.parent() else {
+            continue;
+        };
+        for directive in &directives {
+            for target in directive.paths() {
+                if let Ok(resolved) = resolver.resolve(base_dir, target) {
+                    if let Ok(relative) = resolved.strip_prefix(root) {
+                        targets.push(relative.to_string_lossy().replace('\\', "/"));
+                    }
+                }
+            }
+        }
+    }
+
+    targets
+}
+
+/// Builds the resolver configuration, adding detected load paths if requested.
+fn resolver_config(
+    root: &Path,
+    load_paths: &[PathBuf],
+    auto_load_paths: bool,
+    quiet: bool,
+    verbose: u8,
+) -> ResolverConfig {
+    let mut load_paths = load_paths.to_vec();
+
+    if auto_load_paths {
+        for path in ResolverConfig::detect_load_paths(root) {
+            if verbose > 0 && !quiet {
+                eprintln!("Adding load path: {}", path.display());
+            }
+            load_paths.push(path);
+        }
+    }
+
+    ResolverConfig {
+        load_paths,
+        extensions: vec!["scss".to_string(), "sass".to_string()],
+    }
+}
+
+/// Prints a warning for each entry point reachable from another entry point.
+fn warn_redundant_entries(graph: &DependencyGraph) {
+    for redundant in graph.redundant_entries() {
+        eprintln!(
+            "Warning: Entry point '{}' is already reachable from: {}",
+            redundant.entry,
+            redundant.reachable_from.join(", ")
+        );
+    }
+}
+
+/// Prints a warning for each entry point that likely compiles to empty output.
+fn warn_dead_entries(graph: &DependencyGraph) {
+    for entry in graph.dead_entries() {
+        eprintln!(
+            "Warning: Entry point '{}' produces no CSS output; the bundle may be removable",
+            entry
+        );
+    }
+}
+
+/// Prints a warning for each `@use` namespace shadowing a built-in.
+fn warn_namespace_shadowing(graph: &DependencyGraph) {
+    for shadowing in graph.namespace_shadowing() {
+        eprintln!("Warning: {}", shadowing);
+    }
+}
+
+/// Prints each group of files sharing a module name, with their importers.
+fn warn_duplicate_basenames(graph: &DependencyGraph) {
+    for duplicate in graph.duplicate_basenames() {
+        eprintln!(
+            "Warning: {} files share the module name '{}':",
+            duplicate.files.len(),
+            duplicate.basename
+        );
+        for file in &duplicate.files {
+            if file.imported_by.is_empty() {
+                eprintln!("  {} (not imported)", file.file);
+            } else {
+                eprintln!("  {} <- {}", file.file, file.imported_by.join(", "));
+            }
+        }
+    }
+}
+
 /// Execute the export command.
 ///
 /// Converts a JSON analysis file to a visualization format.
@@ -297,3 +623,306 @@ pub fn export(input: &Path, format: ExportFormat) -> Result<()> {
     print!("{}", output);
     Ok(())
 }
+
+/// Execute the rm command.
+///
+/// Builds the graph from every SCSS file under the root and deletes
+/// `file` only if nothing loads it, unless `force` is set. With `fix`,
+/// the directives loading the file are removed from their importers.
+///
+/// # Errors
+///
+/// Returns an error if the file still has dependents and `force` is not
+/// set, or if reading, editing, or deleting files fails.
+pub fn rm(opts: RmOptions) -> Result<()> {
+    let RmOptions {
+        root,
+        load_paths,
+        auto_load_paths,
+        file,
+        dry_run,
+        force,
+        fix,
+        respect_gitignore,
+        quiet,
+        verbose,
+    } = opts;
+
+    let root = root.canonicalize().context("Failed to resolve root directory")?;
+    let config = resolver_config(&root, load_paths, auto_load_paths, quiet, verbose);
+    let resolver = Resolver::new(config);
+
+    let mut graph = DependencyGraph::new();
+    graph.build_from_root(&root, &resolver, respect_gitignore)?;
+
+    let path = if file.is_absolute() {
+        file.to_path_buf()
+    } else {
+        root.join(file)
+    };
+    let path = path
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve file: {}", file.display()))?;
+    let id = path
+        .strip_prefix(&root)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    if graph.get_node(&id).is_none() {
+        anyhow::bail!("Not an SCSS file under the root: {}", file.display());
+    }
+
+    // Collect every directive that loads the file
+    let mut importers: IndexMap<PathBuf, Vec<Directive>> = IndexMap::new();
+    for (from, to, _) in graph.edges_with_nodes() {
+        if to.id != id {
+            continue;
+        }
+        let content = fs::read_to_string(&from.absolute_path)
+            .with_context(|| format!("Failed to read: {}", from.absolute_path.display()))?;
+        let directives = Parser::parse(&content)
+            .with_context(|| format!("Failed to parse: {}", from.absolute_path.display()))?;
+        let loading: Vec<Directive> = directives
+            .into_iter()
+            .filter(|d| {
+                d.paths().iter().any(|target| {
+                    resolver
+                        .resolve(&from.absolute_path, target)
+                        .map(|resolved| resolved == path)
+                        .unwrap_or(false)
+                })
+            })
+            .collect();
+        importers.insert(from.absolute_path.clone(), loading);
+    }
+
+    let count: usize = importers.values().map(|d| d.len()).sum();
+    if !quiet {
+        if count == 0 {
+            eprintln!("{} has no dependents", id);
+        } else {
+            eprintln!("{} is loaded by {} directive(s):", id, count);
+            for (importer, directives) in &importers {
+                let importer_id = importer.strip_prefix(&root).unwrap_or(importer);
+                for directive in directives {
+                    let location = directive.location();
+                    eprintln!(
+                        "  {}:{}:{} {}",
+                        importer_id.display(),
+                        location.line,
+                        location.column,
+                        directive_label(directive)
+                    );
+                }
+            }
+        }
+    }
+
+    if count > 0 && !force {
+        if dry_run {
+            if !quiet {
+                eprintln!("Would refuse to remove {} without --force", id);
+            }
+            return Ok(());
+        }
+        anyhow::bail!(
+            "{} is still loaded by {} directive(s); use --force to remove it anyway",
+            id,
+            count
+        );
+    }
+
+    if dry_run {
+        if !quiet {
+            eprintln!("Would remove {}", id);
+            if fix && count > 0 {
+                eprintln!("Would remove {} directive(s) from importers", count);
+            }
+        }
+        return Ok(());
+    }
+
+    // Edit importers before deleting, so a failed edit leaves the file in place
+    if fix {
+        let mut edits = Vec::new();
+        let mut failed = Vec::new();
+
+        for (importer, directives) in &importers {
+            let mut content = fs::read_to_string(importer)
+                .with_context(|| format!("Failed to read: {}", importer.display()))?;
+
+            // Remove from the bottom up so earlier locations stay valid
+            let mut directives: Vec<&Directive> = directives.iter().collect();
+            directives.sort_by_key(|d| std::cmp::Reverse((d.location().line, d.location().column)));
+
+            for directive in directives {
+                let location = directive.location();
+                let edited = if directive.paths().len() > 1 {
+                    None
+                } else {
+                    Parser::remove_directive(&content, location)
+                };
+                match edited {
+                    Some(edited) => content = edited,
+                    None => failed.push(format!(
+                        "{}:{}:{} {}",
+                        importer.strip_prefix(&root).unwrap_or(importer).display(),
+                        location.line,
+                        location.column,
+                        directive_label(directive)
+                    )),
+                }
+            }
+
+            edits.push((importer, content));
+        }
+
+        // Only touch importers once every directive can be removed
+        if !failed.is_empty() {
+            anyhow::bail!(
+                "Could not remove {} directive(s); edit them manually or rerun without --fix:\n  {}",
+                failed.len(),
+                failed.join("\n  ")
+            );
+        }
+
+        for (importer, content) in edits {
+            fs::write(importer, content)
+                .with_context(|| format!("Failed to write: {}", importer.display()))?;
+        }
+    } else if count > 0 && !quiet {
+        eprintln!("Warning: {} directive(s) now load a missing file", count);
+    }
+
+    fs::remove_file(&path).with_context(|| format!("Failed to remove: {}", path.display()))?;
+    if !quiet {
+        eprintln!("Removed {}", id);
+    }
+
+    Ok(())
+}
+
+/// Formats a directive for messages, e.g. `@use "variables"`.
+fn directive_label(directive: &Directive) -> String {
+    let keyword = match directive {
+        Directive::Use(_) => "@use",
+        Directive::Forward(_) => "@forward",
+        Directive::Import(_) => "@import",
+    };
+    let paths: Vec<String> = directive
+        .paths()
+        .iter()
+        .map(|p| format!("\"{}\"", p))
+        .collect();
+    format!("{} {}", keyword, paths.join(", "))
+}
+
+/// Execute the serve command.
+///
+/// Loads each JSON analysis and serves them all from one web server.
+pub fn serve(projects: &[ProjectInput], port: u16) -> Result<()> {
+    let mut schemas = IndexMap::new();
+
+    for project in projects {
+        let content = fs::read_to_string(&project.path)
+            .with_context(|| format!("Failed to read input file: {}", project.path.display()))?;
+        let schema: OutputSchema = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse input JSON: {}", project.path.display()))?;
+
+        if schemas.insert(project.name.clone(), schema).is_some() {
+            anyhow::bail!("Duplicate project name: {}", project.name);
+        }
+    }
+
+    let rt = tokio::runtime::Runtime::new()
+        .context("Failed to create async runtime")?;
+    rt.block_on(crate::web::serve_projects(schemas, port))
+}
+
+/// Execute the bench command.
+///
+/// Generates a synthetic project in a temporary directory, then times
+/// parsing every file, building the graph, and running the analyzer.
+/// The best time of `iterations` runs is reported for each stage.
+pub fn bench(files: usize, fan_out: usize, iterations: usize) -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("sass-dep-bench-{}", std::process::id()));
+    let config = SyntheticConfig {
+        files,
+        fan_out,
+        ..SyntheticConfig::default()
+    };
+
+    let result = generate(&dir, &config)
+        .context("Failed to generate synthetic project")
+        .and_then(|project| run_bench(&project, iterations.max(1)));
+    let _ = fs::remove_dir_all(&dir);
+
+    let timings = result?;
+    println!(
+        "{} files, {} dependencies, best of {} runs",
+        files + 1,
+        timings.dependencies,
+        iterations.max(1)
+    );
+    for (stage, duration) in [
+        ("parse", timings.parse),
+        ("build", timings.build),
+        ("analyze", timings.analyze),
+    ] {
+        println!(
+            "{:<8} {:>10.2} ms {:>12.0} files/s",
+            stage,
+            duration.as_secs_f64() * 1000.0,
+            (files + 1) as f64 / duration.as_secs_f64().max(f64::EPSILON)
+        );
+    }
+
+    Ok(())
+}
+
+/// Best timings for each benchmark stage.
+struct BenchTimings {
+    dependencies: usize,
+    parse: Duration,
+    build: Duration,
+    analyze: Duration,
+}
+
+/// Times each stage against a generated project.
+fn run_bench(project: &SyntheticProject, iterations: usize) -> Result<BenchTimings> {
+    let root = project.root.canonicalize().context("Failed to resolve bench root")?;
+    let resolver = Resolver::default();
+
+    let mut parse = Duration::MAX;
+    let mut build = Duration::MAX;
+    let mut analyze = Duration::MAX;
+    let mut graph = DependencyGraph::new();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        for file in &project.files {
+            let content = fs::read_to_string(file)
+                .with_context(|| format!("Failed to read: {}", file.display()))?;
+            Parser::parse(&content)
+                .with_context(|| format!("Failed to parse: {}", file.display()))?;
+        }
+        parse = parse.min(start.elapsed());
+
+        let start = Instant::now();
+        graph = DependencyGraph::new();
+        graph.build_from_entry(&project.entry, &resolver, &root)?;
+        build = build.min(start.elapsed());
+
+        let mut analyzed = graph.clone();
+        let start = Instant::now();
+        Analyzer::default().analyze(&mut analyzed);
+        analyze = analyze.min(start.elapsed());
+    }
+
+    Ok(BenchTimings {
+        dependencies: graph.edge_count(),
+        parse,
+        build,
+        analyze,
+    })
+}
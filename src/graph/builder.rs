@@ -4,22 +4,28 @@
 //! recursively discovers and adds dependencies.
 
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use ignore::WalkBuilder;
 use indexmap::IndexMap;
 use petgraph::graph::DiGraph;
-use walkdir::WalkDir;
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
 
 use super::node::{DependencyEdge, DirectiveType, EdgeMeta, FileNode, NodeFlag};
 use super::NodeId;
-use crate::parser::{Directive, Namespace, Parser};
+use crate::analyzer::{
+    detect_namespace_shadowing, DuplicateBasename, NamespaceShadowing, RedundantEntry,
+};
+use crate::parser::{Directive, Namespace, ParseError, Parser};
 use crate::resolver::Resolver;
 
 /// A dependency graph representing SCSS file relationships.
 ///
 /// The graph uses `petgraph::DiGraph` for efficient graph operations
 /// and `IndexMap` for deterministic node ordering.
+#[derive(Clone)]
 pub struct DependencyGraph {
     /// The underlying directed graph.
     graph: DiGraph<FileNode, DependencyEdge>,
@@ -29,6 +35,16 @@ pub struct DependencyGraph {
     entry_points: HashSet<String>,
     /// Detected cycles (populated after analysis).
     cycles: Vec<Vec<String>>,
+    /// Entry points reachable from other entry points (populated after analysis).
+    redundant_entries: Vec<RedundantEntry>,
+    /// `@use` namespaces shadowing built-ins (populated during construction).
+    namespace_shadowing: Vec<NamespaceShadowing>,
+    /// Files sharing a module name (populated after analysis).
+    duplicate_basenames: Vec<DuplicateBasename>,
+    /// Entry points that likely compile to empty output (populated after analysis).
+    dead_entries: Vec<String>,
+    /// Files whose dependencies have already been followed.
+    processed: HashSet<String>,
 }
 
 impl DependencyGraph {
@@ -39,6 +55,11 @@ impl DependencyGraph {
             node_index: IndexMap::new(),
             entry_points: HashSet::new(),
             cycles: Vec::new(),
+            redundant_entries: Vec::new(),
+            namespace_shadowing: Vec::new(),
+            duplicate_basenames: Vec::new(),
+            dead_entries: Vec::new(),
+            processed: HashSet::new(),
         }
     }
 
@@ -83,13 +104,181 @@ impl DependencyGraph {
         Ok(*self.node_index.get(&entry_id).unwrap())
     }
 
+    /// Re-analyzes a single file after its contents changed.
+    ///
+    /// The file is re-parsed from `contents`, its outgoing edges are replaced,
+    /// and any newly referenced files are discovered from disk. Metrics and
+    /// analysis-derived flags of every node whose results may have changed are
+    /// reset; run the [`Analyzer`](crate::analyzer::Analyzer) again to
+    /// recompute them. The `Orphan` flag of those nodes is updated directly,
+    /// since it depends on reachability from the entry points.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the changed file (must already be in the graph)
+    /// * `contents` - The new source of the file
+    /// * `resolver` - Resolver for import paths
+    /// * `root` - Project root directory for computing relative paths
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the dirty nodes: the file itself, its old and new direct
+    /// dependencies, everything reachable from them, and every file that
+    /// transitively depends on the changed file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is not part of the graph, or if parsing
+    /// the new contents or a newly discovered dependency fails.
+    pub fn update_file(
+        &mut self,
+        path: &Path,
+        contents: &str,
+        resolver: &Resolver,
+        root: &Path,
+    ) -> Result<HashSet<String>> {
+        let path = path.canonicalize().context("Failed to canonicalize file path")?;
+        let id = self.get_file_id(&path, root);
+        let idx = *self
+            .node_index
+            .get(&id)
+            .with_context(|| format!("File is not part of the graph: {}", id))?;
+
+        let directives = Parser::parse(contents)
+            .with_context(|| format!("Failed to parse: {}", path.display()))?;
+        self.record_namespace_shadowing(&id, &directives);
+        self.graph[idx].emits_css = Parser::emits_css(contents);
+        self.graph[idx].has_unresolved = false;
+
+        // Drop the current outgoing edges. Removal swaps the last edge into
+        // the freed slot, so remove from the highest index down.
+        let old_targets: Vec<NodeId> = self
+            .graph
+            .neighbors_directed(idx, Direction::Outgoing)
+            .collect();
+        let mut old_edges: Vec<_> = self
+            .graph
+            .edges_directed(idx, Direction::Outgoing)
+            .map(|e| e.id())
+            .collect();
+        old_edges.sort_unstable_by(|a, b| b.cmp(a));
+        for edge in old_edges {
+            self.graph.remove_edge(edge);
+        }
+
+        // Re-create edges from the new directives
+        for directive in directives {
+            self.process_directive(&directive, &path, resolver, root, &id)?;
+        }
+
+        // Collect every node whose metrics may have changed
+        let new_targets: Vec<NodeId> = self
+            .graph
+            .neighbors_directed(idx, Direction::Outgoing)
+            .collect();
+        let mut dirty = self.reachable(std::iter::once(idx), Direction::Incoming);
+        dirty.extend(self.reachable(
+            old_targets.into_iter().chain(new_targets),
+            Direction::Outgoing,
+        ));
+
+        // Orphan status follows reachability from the entry points
+        let entries: Vec<NodeId> = self
+            .entry_points
+            .iter()
+            .filter_map(|id| self.node_index.get(id).copied())
+            .collect();
+        let reachable = self.reachable(entries, Direction::Outgoing);
+
+        let dirty_ids: HashSet<String> = dirty
+            .into_iter()
+            .map(|i| {
+                let node = &mut self.graph[i];
+                node.reset_analysis();
+                if reachable.contains(&i) {
+                    node.remove_flag(&NodeFlag::Orphan);
+                } else {
+                    node.add_flag(NodeFlag::Orphan);
+                }
+                node.id.clone()
+            })
+            .collect();
+
+        Ok(dirty_ids)
+    }
+
+    /// Returns the files whose rule checks may be affected by changes.
+    ///
+    /// This is the changed files themselves, every file that transitively
+    /// depends on a changed file, and everything downstream of the files
+    /// the changed files import now or imported before the change, since
+    /// added and removed edges shift fan-in, depth and entry reach through
+    /// the whole subtree. IDs that are not part of the graph are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - IDs of the changed files
+    /// * `previous_targets` - IDs of the files the changed files imported
+    ///   before the change
+    pub fn affected_by<'a>(
+        &self,
+        ids: impl IntoIterator<Item = &'a str>,
+        previous_targets: impl IntoIterator<Item = &'a str>,
+    ) -> HashSet<String> {
+        let lookup = |id: &str| self.node_index.get(id).copied();
+        let changed: Vec<NodeId> = ids.into_iter().filter_map(lookup).collect();
+
+        let mut affected = self.reachable(changed.iter().copied(), Direction::Incoming);
+        affected.extend(self.reachable(
+            changed
+                .iter()
+                .copied()
+                .chain(previous_targets.into_iter().filter_map(lookup)),
+            Direction::Outgoing,
+        ));
+
+        affected.into_iter().map(|i| self.graph[i].id.clone()).collect()
+    }
+
+    /// Returns all nodes reachable from `start` in the given direction,
+    /// including the start nodes themselves.
+    fn reachable(
+        &self,
+        start: impl IntoIterator<Item = NodeId>,
+        direction: Direction,
+    ) -> HashSet<NodeId> {
+        let mut visited = HashSet::new();
+        let mut stack: Vec<NodeId> = start.into_iter().collect();
+
+        while let Some(current) = stack.pop() {
+            if visited.insert(current) {
+                stack.extend(self.graph.neighbors_directed(current, direction));
+            }
+        }
+
+        visited
+    }
+
     /// Processes a file, extracting and following its dependencies.
     fn process_file(&mut self, path: &Path, resolver: &Resolver, root: &Path) -> Result<()> {
+        // Mark before recursing so shared dependencies and cycles are
+        // only followed once
+        let from_id = self.get_file_id(path, root);
+        if !self.processed.insert(from_id.clone()) {
+            return Ok(());
+        }
+
         // Parse the file
-        let directives = Parser::parse_file(path)
+        let content = std::fs::read_to_string(path)
+            .map_err(ParseError::from)
+            .with_context(|| format!("Failed to parse: {}", path.display()))?;
+        let directives = Parser::parse(&content)
             .with_context(|| format!("Failed to parse: {}", path.display()))?;
 
-        let from_id = self.get_file_id(path, root);
+        self.record_namespace_shadowing(&from_id, &directives);
+        if let Some(node) = self.get_node_mut(&from_id) {
+            node.emits_css = Parser::emits_css(&content);
+        }
 
         // Process each directive
         for directive in directives {
@@ -99,6 +288,13 @@ impl DependencyGraph {
         Ok(())
     }
 
+    /// Replaces the namespace shadowing findings for a file.
+    fn record_namespace_shadowing(&mut self, file_id: &str, directives: &[Directive]) {
+        self.namespace_shadowing.retain(|s| s.file != file_id);
+        self.namespace_shadowing
+            .extend(detect_namespace_shadowing(file_id, directives));
+    }
+
     /// Checks if a target is a Sass built-in module.
     ///
     /// Built-in modules like `sass:math`, `sass:map`, `sass:color`, etc.
@@ -130,6 +326,9 @@ impl DependencyGraph {
                 Ok(p) => p,
                 Err(e) => {
                     // Log warning but continue (soft failure)
+                    if let Some(node) = self.get_node_mut(from_id) {
+                        node.has_unresolved = true;
+                    }
                     eprintln!(
                         "Warning: Could not resolve '{}' from '{}': {}",
                         target,
@@ -142,8 +341,6 @@ impl DependencyGraph {
 
             // Add the target file
             let to_id = self.add_file(&resolved, root)?;
-            let already_processed = self.node_index.contains_key(&to_id)
-                && self.get_node(&to_id).map(|n| !n.flags.is_empty() || n.metrics.fan_in > 0 || n.metrics.fan_out > 0).unwrap_or(false);
 
             // Create edge
             let (directive_type, meta) = match directive {
@@ -170,12 +367,8 @@ impl DependencyGraph {
             // Add edge to graph
             self.add_edge(from_id, &to_id, edge);
 
-            // Recursively process the target if not already done
-            // Check if we've already started processing this file
-            let is_new = !already_processed;
-            if is_new {
-                self.process_file(&resolved, resolver, root)?;
-            }
+            // Recursively process the target (no-op if already done)
+            self.process_file(&resolved, resolver, root)?;
         }
 
         Ok(())
@@ -218,18 +411,21 @@ impl DependencyGraph {
     /// Discovers orphan files in the project root.
     ///
     /// Orphan files are SCSS files that are not reachable from any entry point.
-    pub fn discover_orphans(&mut self, root: &Path, _resolver: &Resolver) -> Result<()> {
-        for entry in WalkDir::new(root)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.path()
-                    .extension()
-                    .map(|ext| ext == "scss" || ext == "sass")
-                    .unwrap_or(false)
-            })
-        {
-            let path = entry.path().canonicalize()?;
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Project root directory to scan
+    /// * `resolver` - Resolver for import paths
+    /// * `respect_gitignore` - Skip paths excluded by `.gitignore`, `.ignore`,
+    ///   and git exclude files, so build output and vendored trees are not
+    ///   reported as orphans
+    pub fn discover_orphans(
+        &mut self,
+        root: &Path,
+        _resolver: &Resolver,
+        respect_gitignore: bool,
+    ) -> Result<()> {
+        for path in Self::scss_files(root, respect_gitignore)? {
             let id = self.get_file_id(&path, root);
 
             if !self.node_index.contains_key(&id) {
@@ -243,6 +439,60 @@ impl DependencyGraph {
         Ok(())
     }
 
+    /// Builds the graph from every SCSS file under the root.
+    ///
+    /// Unlike [`build_from_entry`](Self::build_from_entry), no entry points
+    /// are marked. This gives a complete view of who depends on what, which
+    /// is needed before deleting or moving files.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Project root directory to scan
+    /// * `resolver` - Resolver for import paths
+    /// * `respect_gitignore` - Skip paths excluded by ignore files
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or parsed.
+    pub fn build_from_root(
+        &mut self,
+        root: &Path,
+        resolver: &Resolver,
+        respect_gitignore: bool,
+    ) -> Result<()> {
+        for path in Self::scss_files(root, respect_gitignore)? {
+            self.add_file(&path, root)?;
+            self.process_file(&path, resolver, root)?;
+        }
+
+        Ok(())
+    }
+
+    /// Lists the canonical paths of all SCSS files under the root.
+    fn scss_files(root: &Path, respect_gitignore: bool) -> Result<Vec<PathBuf>> {
+        let walker = WalkBuilder::new(root)
+            .hidden(false)
+            .parents(respect_gitignore)
+            .ignore(respect_gitignore)
+            .git_ignore(respect_gitignore)
+            .git_global(respect_gitignore)
+            .git_exclude(respect_gitignore)
+            .require_git(false)
+            .build();
+
+        walker
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().map(|t| t.is_file()).unwrap_or(false))
+            .filter(|e| {
+                e.path()
+                    .extension()
+                    .map(|ext| ext == "scss" || ext == "sass")
+                    .unwrap_or(false)
+            })
+            .map(|e| Ok(e.path().canonicalize()?))
+            .collect()
+    }
+
     /// Returns the number of nodes in the graph.
     pub fn node_count(&self) -> usize {
         self.graph.node_count()
@@ -300,23 +550,60 @@ impl DependencyGraph {
         &self.cycles
     }
 
+    /// Sets the detected redundant entry points.
+    pub fn set_redundant_entries(&mut self, redundant: Vec<RedundantEntry>) {
+        self.redundant_entries = redundant;
+    }
+
+    /// Returns the detected redundant entry points.
+    pub fn redundant_entries(&self) -> &[RedundantEntry] {
+        &self.redundant_entries
+    }
+
+    /// Sets the detected duplicate basenames.
+    pub fn set_duplicate_basenames(&mut self, duplicates: Vec<DuplicateBasename>) {
+        self.duplicate_basenames = duplicates;
+    }
+
+    /// Returns the groups of files sharing a module name.
+    pub fn duplicate_basenames(&self) -> &[DuplicateBasename] {
+        &self.duplicate_basenames
+    }
+
+    /// Sets the detected dead entry points.
+    pub fn set_dead_entries(&mut self, dead: Vec<String>) {
+        self.dead_entries = dead;
+    }
+
+    /// Returns the entry points that likely compile to empty output.
+    pub fn dead_entries(&self) -> &[String] {
+        &self.dead_entries
+    }
+
+    /// Returns the `@use` namespaces that shadow built-in modules or CSS keywords.
+    pub fn namespace_shadowing(&self) -> &[NamespaceShadowing] {
+        &self.namespace_shadowing
+    }
+
     /// Returns all edges as (from_id, to_id, edge) tuples.
+    ///
+    /// Prefer [`edges_with_nodes`](Self::edges_with_nodes) when node
+    /// metadata is needed, to avoid looking up each endpoint by ID.
     pub fn edges(&self) -> impl Iterator<Item = (&str, &str, &DependencyEdge)> {
-        self.graph.edge_indices().map(move |idx| {
-            let (from_idx, to_idx) = self.graph.edge_endpoints(idx).unwrap();
-            let from_id = self
-                .node_index
-                .iter()
-                .find(|(_, &i)| i == from_idx)
-                .map(|(id, _)| id.as_str())
-                .unwrap();
-            let to_id = self
-                .node_index
-                .iter()
-                .find(|(_, &i)| i == to_idx)
-                .map(|(id, _)| id.as_str())
-                .unwrap();
-            (from_id, to_id, &self.graph[idx])
+        self.edges_with_nodes()
+            .map(|(from, to, edge)| (from.id.as_str(), to.id.as_str(), edge))
+    }
+
+    /// Returns all edges as (from_node, to_node, edge) tuples.
+    pub fn edges_with_nodes(
+        &self,
+    ) -> impl Iterator<Item = (&FileNode, &FileNode, &DependencyEdge)> {
+        self.graph.edge_references().map(move |edge| {
+            (
+                &self.graph[edge.source()],
+                &self.graph[edge.target()],
+                edge.weight(),
+            )
         })
     }
 }
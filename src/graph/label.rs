@@ -0,0 +1,101 @@
+//! Node label strategies for rendered output.
+//!
+//! Full paths make large diagrams unreadable, while basenames alone
+//! are ambiguous. This module lets renderers choose a trade-off.
+
+use std::str::FromStr;
+
+use super::node::NodeMetrics;
+
+/// How nodes are labeled in rendered diagrams.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LabelStrategy {
+    /// Full file ID (path relative to the root).
+    Full,
+    /// File name only (default).
+    #[default]
+    Basename,
+    /// File name with its parent directory.
+    Parent,
+    /// Custom template with `{placeholder}` substitution.
+    ///
+    /// Supported placeholders: `{path}`, `{basename}`, `{parent}`,
+    /// `{fan_in}`, `{fan_out}`, `{depth}`, `{transitive_deps}`.
+    Template(String),
+}
+
+impl LabelStrategy {
+    /// Returns the label for a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The node's file ID
+    /// * `metrics` - The node's computed metrics (used by templates)
+    pub fn label(&self, id: &str, metrics: &NodeMetrics) -> String {
+        let (parent, basename) = match id.rsplit_once('/') {
+            Some((dir, name)) => (dir.rsplit('/').next().unwrap_or(dir), name),
+            None => ("", id),
+        };
+
+        match self {
+            LabelStrategy::Full => id.to_string(),
+            LabelStrategy::Basename => basename.to_string(),
+            LabelStrategy::Parent if parent.is_empty() => basename.to_string(),
+            LabelStrategy::Parent => format!("{}/{}", parent, basename),
+            LabelStrategy::Template(template) => template
+                .replace("{path}", id)
+                .replace("{basename}", basename)
+                .replace("{parent}", parent)
+                .replace("{fan_in}", &metrics.fan_in.to_string())
+                .replace("{fan_out}", &metrics.fan_out.to_string())
+                .replace("{depth}", &metrics.depth.to_string())
+                .replace("{transitive_deps}", &metrics.transitive_deps.to_string()),
+        }
+    }
+}
+
+impl FromStr for LabelStrategy {
+    type Err = String;
+
+    /// Parses `full`, `basename`, `parent`, or a template containing `{`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(LabelStrategy::Full),
+            "basename" => Ok(LabelStrategy::Basename),
+            "parent" => Ok(LabelStrategy::Parent),
+            template if template.contains('{') => Ok(LabelStrategy::Template(template.to_string())),
+            other => Err(format!(
+                "unknown label strategy '{}' (expected full, basename, parent, or a template)",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_strategies() {
+        let metrics = NodeMetrics::default();
+        let id = "src/components/_button.scss";
+
+        assert_eq!(LabelStrategy::Full.label(id, &metrics), id);
+        assert_eq!(LabelStrategy::Basename.label(id, &metrics), "_button.scss");
+        assert_eq!(LabelStrategy::Parent.label(id, &metrics), "components/_button.scss");
+        assert_eq!(LabelStrategy::Parent.label("main.scss", &metrics), "main.scss");
+    }
+
+    #[test]
+    fn label_template() {
+        let metrics = NodeMetrics {
+            fan_in: 3,
+            ..Default::default()
+        };
+        let strategy: LabelStrategy = "{basename} ({fan_in})".parse().unwrap();
+
+        assert_eq!(strategy.label("src/_a.scss", &metrics), "_a.scss (3)");
+        assert!("nonsense".parse::<LabelStrategy>().is_err());
+    }
+}
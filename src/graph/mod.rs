@@ -30,10 +30,16 @@
 //! ```
 
 mod builder;
+mod label;
 mod node;
+mod simulate;
 
 pub use builder::DependencyGraph;
-pub use node::{DependencyEdge, DirectiveType, EdgeMeta, FileNode, NodeFlag, NodeMetrics};
+pub use label::LabelStrategy;
+pub use node::{
+    DependencyEdge, DirectiveType, EdgeMeta, FileNode, NodeFlag, NodeMetrics, Position,
+};
+pub use simulate::{MetricChange, SimulationReport};
 
 /// Type alias for node indices in the graph.
 pub type NodeId = petgraph::graph::NodeIndex;
@@ -20,6 +20,12 @@ pub struct FileNode {
     pub metrics: NodeMetrics,
     /// Flags assigned to this node.
     pub flags: Vec<NodeFlag>,
+    /// Layout coordinates (populated by the layout pass).
+    pub position: Option<Position>,
+    /// Whether the file likely produces CSS on its own.
+    pub emits_css: bool,
+    /// Whether the file loads targets that could not be resolved.
+    pub has_unresolved: bool,
 }
 
 impl FileNode {
@@ -35,6 +41,9 @@ impl FileNode {
             absolute_path,
             metrics: NodeMetrics::default(),
             flags: Vec::new(),
+            position: None,
+            emits_css: false,
+            has_unresolved: false,
         }
     }
 
@@ -54,10 +63,21 @@ impl FileNode {
     pub fn has_flag(&self, flag: &NodeFlag) -> bool {
         self.flags.contains(flag)
     }
+
+    /// Clears metrics and analysis-derived flags.
+    ///
+    /// `EntryPoint` and `Orphan` are kept since they are assigned during
+    /// graph construction rather than by the analyzer; `update_file`
+    /// recomputes `Orphan` for the nodes it resets.
+    pub fn reset_analysis(&mut self) {
+        self.metrics = NodeMetrics::default();
+        self.flags
+            .retain(|f| matches!(f, NodeFlag::EntryPoint | NodeFlag::Orphan));
+    }
 }
 
 /// Computed metrics for a file node.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NodeMetrics {
     /// Number of files that depend on this file (in-degree).
     pub fan_in: usize,
@@ -69,6 +89,17 @@ pub struct NodeMetrics {
     pub transitive_deps: usize,
 }
 
+/// Coordinates of a node computed by the layout pass.
+///
+/// Positions refer to the top-left corner of the node box.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Position {
+    /// Horizontal coordinate.
+    pub x: f64,
+    /// Vertical coordinate.
+    pub y: f64,
+}
+
 /// Flags that can be assigned to nodes based on analysis.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -109,6 +140,8 @@ pub struct DependencyEdge {
     pub location: Location,
     /// Additional metadata about the edge.
     pub meta: EdgeMeta,
+    /// Index of the detected cycle this edge belongs to, if any.
+    pub cycle_index: Option<usize>,
 }
 
 impl DependencyEdge {
@@ -118,6 +151,7 @@ impl DependencyEdge {
             directive_type,
             location,
             meta: EdgeMeta::default(),
+            cycle_index: None,
         }
     }
 
@@ -127,8 +161,14 @@ impl DependencyEdge {
             directive_type,
             location,
             meta,
+            cycle_index: None,
         }
     }
+
+    /// Returns whether this edge participates in a cycle.
+    pub fn in_cycle(&self) -> bool {
+        self.cycle_index.is_some()
+    }
 }
 
 /// Type of directive that created a dependency.
@@ -174,6 +214,7 @@ mod tests {
         assert_eq!(node.id, "src/main.scss");
         assert_eq!(node.absolute_path, PathBuf::from("/project/src/main.scss"));
         assert!(node.flags.is_empty());
+        assert!(node.position.is_none());
     }
 
     #[test]
@@ -190,6 +231,20 @@ mod tests {
         assert!(!node.has_flag(&NodeFlag::EntryPoint));
     }
 
+    #[test]
+    fn file_node_reset_analysis() {
+        let mut node = FileNode::new("test.scss".to_string(), PathBuf::from("/test.scss"));
+        node.add_flag(NodeFlag::EntryPoint);
+        node.add_flag(NodeFlag::Leaf);
+        node.add_flag(NodeFlag::InCycle);
+        node.metrics.fan_in = 3;
+
+        node.reset_analysis();
+
+        assert_eq!(node.flags, vec![NodeFlag::EntryPoint]);
+        assert_eq!(node.metrics.fan_in, 0);
+    }
+
     #[test]
     fn dependency_edge_new() {
         let edge = DependencyEdge::new(DirectiveType::Use, Location::new(1, 1));
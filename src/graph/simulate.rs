@@ -0,0 +1,129 @@
+//! What-if analysis for edge changes.
+//!
+//! This module previews how adding or removing dependencies would affect
+//! cycles and metrics, without modifying the graph. Refactoring tools can
+//! use it to answer questions like "what if I removed this import?".
+
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+
+use super::builder::DependencyGraph;
+use super::node::{DependencyEdge, DirectiveType, NodeMetrics};
+use crate::analyzer::Analyzer;
+use crate::parser::Location;
+
+/// Result of simulating edge changes.
+#[derive(Debug, Clone, Default)]
+pub struct SimulationReport {
+    /// Cycles present before the change but not after, as sorted file IDs.
+    pub cycles_broken: Vec<Vec<String>>,
+    /// Cycles present after the change but not before, as sorted file IDs.
+    pub cycles_created: Vec<Vec<String>>,
+    /// Files whose metrics change, in node order.
+    pub metric_changes: Vec<MetricChange>,
+}
+
+/// Metrics of a file before and after a simulated change.
+#[derive(Debug, Clone)]
+pub struct MetricChange {
+    /// File ID.
+    pub file: String,
+    /// Metrics before the change.
+    pub before: NodeMetrics,
+    /// Metrics after the change.
+    pub after: NodeMetrics,
+}
+
+impl DependencyGraph {
+    /// Simulates removing and adding edges without mutating the graph.
+    ///
+    /// Both the current graph and the modified copy are analyzed with the
+    /// default [`Analyzer`], and the differences are reported. A cycle that
+    /// only shrinks shows up as one broken and one created cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `removals` - `(from, to)` file ID pairs of edges to remove
+    /// * `additions` - `(from, to)` file ID pairs of edges to add (as `@use`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file ID is not in the graph or a removed
+    /// edge does not exist.
+    pub fn simulate(
+        &self,
+        removals: &[(&str, &str)],
+        additions: &[(&str, &str)],
+    ) -> Result<SimulationReport> {
+        let mut before = self.clone();
+        let mut after = self.clone();
+
+        for (from, to) in removals {
+            let (from_idx, to_idx) = after.endpoints(from, to)?;
+            let Some(edge) = after.inner().find_edge(from_idx, to_idx) else {
+                bail!("No dependency from '{}' to '{}'", from, to);
+            };
+            after.inner_mut().remove_edge(edge);
+        }
+
+        for (from, to) in additions {
+            let (from_idx, to_idx) = after.endpoints(from, to)?;
+            if after.inner().find_edge(from_idx, to_idx).is_none() {
+                let edge = DependencyEdge::new(DirectiveType::Use, Location::default());
+                after.inner_mut().add_edge(from_idx, to_idx, edge);
+            }
+        }
+
+        let analyzer = Analyzer::default();
+        analyzer.analyze(&mut before);
+        analyzer.analyze(&mut after);
+
+        let cycles_before = normalized_cycles(&before);
+        let cycles_after = normalized_cycles(&after);
+
+        let metric_changes = before
+            .nodes()
+            .filter_map(|(id, node)| {
+                let after_node = after.get_node(id)?;
+                (node.metrics != after_node.metrics).then(|| MetricChange {
+                    file: id.clone(),
+                    before: node.metrics.clone(),
+                    after: after_node.metrics.clone(),
+                })
+            })
+            .collect();
+
+        Ok(SimulationReport {
+            cycles_broken: cycles_before.difference(&cycles_after).cloned().collect(),
+            cycles_created: cycles_after.difference(&cycles_before).cloned().collect(),
+            metric_changes,
+        })
+    }
+
+    /// Looks up the node indices of an edge's endpoints.
+    fn endpoints(&self, from: &str, to: &str) -> Result<(super::NodeId, super::NodeId)> {
+        let from_idx = *self
+            .node_index()
+            .get(from)
+            .with_context(|| format!("File is not part of the graph: {}", from))?;
+        let to_idx = *self
+            .node_index()
+            .get(to)
+            .with_context(|| format!("File is not part of the graph: {}", to))?;
+        Ok((from_idx, to_idx))
+    }
+}
+
+/// Returns the detected cycles with members sorted, for set comparison.
+fn normalized_cycles(graph: &DependencyGraph) -> HashSet<Vec<String>> {
+    graph
+        .get_cycles()
+        .iter()
+        .map(|cycle| {
+            let mut cycle = cycle.clone();
+            cycle.sort();
+            cycle
+        })
+        .collect()
+}
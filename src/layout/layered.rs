@@ -0,0 +1,153 @@
+//! Layered layout implementation.
+//!
+//! This module implements a simplified Sugiyama layout: layer assignment
+//! by longest path over the condensed graph, followed by barycenter
+//! ordering within each layer.
+
+use std::collections::HashMap;
+
+use petgraph::algo::tarjan_scc;
+use petgraph::Direction;
+
+use super::LayoutConfig;
+use crate::graph::{DependencyGraph, NodeId, Position};
+
+/// Computes positions for all nodes and stores them on the graph.
+///
+/// Dependencies are placed to the right of their dependents. Nodes that
+/// form a cycle share the same layer.
+///
+/// # Arguments
+///
+/// * `graph` - The dependency graph to lay out
+/// * `config` - Node sizes, spacing, and number of ordering sweeps
+pub fn compute_layout(graph: &mut DependencyGraph, config: &LayoutConfig) {
+    let mut layers = assign_layers(graph);
+    order_layers(graph, &mut layers, config.iterations);
+
+    let widest = layers.iter().map(|l| l.len()).max().unwrap_or(0);
+    let mut positions = Vec::with_capacity(graph.node_count());
+
+    for (layer_idx, layer) in layers.iter().enumerate() {
+        // Center shorter layers against the widest one
+        let offset = (widest - layer.len()) as f64 / 2.0;
+        let x = layer_idx as f64 * (config.node_width + config.layer_gap);
+
+        for (order, &idx) in layer.iter().enumerate() {
+            let y = (order as f64 + offset) * (config.node_height + config.node_gap);
+            positions.push((idx, Position { x, y }));
+        }
+    }
+
+    let inner = graph.inner_mut();
+    for (idx, position) in positions {
+        inner[idx].position = Some(position);
+    }
+}
+
+/// Groups nodes into layers using longest-path layering.
+///
+/// Strongly connected components are treated as a single unit so the
+/// layering is well defined in the presence of cycles.
+fn assign_layers(graph: &DependencyGraph) -> Vec<Vec<NodeId>> {
+    let inner = graph.inner();
+
+    // Tarjan's algorithm yields components in reverse topological order
+    let sccs = tarjan_scc(inner);
+    let mut component: HashMap<NodeId, usize> = HashMap::new();
+    for (i, scc) in sccs.iter().enumerate() {
+        for &idx in scc {
+            component.insert(idx, i);
+        }
+    }
+
+    let mut component_layer = vec![0usize; sccs.len()];
+    for (i, scc) in sccs.iter().enumerate().rev() {
+        component_layer[i] = scc
+            .iter()
+            .flat_map(|&idx| inner.neighbors_directed(idx, Direction::Incoming))
+            .map(|pred| component[&pred])
+            .filter(|&c| c != i)
+            .map(|c| component_layer[c] + 1)
+            .max()
+            .unwrap_or(0);
+    }
+
+    let layer_count = component_layer.iter().max().map(|m| m + 1).unwrap_or(0);
+    let mut layers = vec![Vec::new(); layer_count];
+
+    // Iterate in node index order so the initial ordering is deterministic
+    for &idx in graph.node_index().values() {
+        layers[component_layer[component[&idx]]].push(idx);
+    }
+
+    layers
+}
+
+/// Reorders nodes within layers using the barycenter heuristic.
+///
+/// Sweeps alternate between aligning each layer with its dependents
+/// and with its dependencies.
+fn order_layers(graph: &DependencyGraph, layers: &mut [Vec<NodeId>], iterations: usize) {
+    let inner = graph.inner();
+
+    let mut order: HashMap<NodeId, usize> = HashMap::new();
+    for layer in layers.iter() {
+        for (i, &idx) in layer.iter().enumerate() {
+            order.insert(idx, i);
+        }
+    }
+
+    for iteration in 0..iterations {
+        let direction = if iteration % 2 == 0 {
+            Direction::Incoming
+        } else {
+            Direction::Outgoing
+        };
+
+        let layer_indices: Vec<usize> = if direction == Direction::Incoming {
+            (0..layers.len()).collect()
+        } else {
+            (0..layers.len()).rev().collect()
+        };
+
+        for layer_idx in layer_indices {
+            let layer = &mut layers[layer_idx];
+
+            let mut keyed: Vec<(f64, NodeId)> = layer
+                .iter()
+                .map(|&idx| {
+                    let neighbors: Vec<usize> = inner
+                        .neighbors_directed(idx, direction)
+                        .map(|n| order[&n])
+                        .collect();
+                    let key = if neighbors.is_empty() {
+                        order[&idx] as f64
+                    } else {
+                        neighbors.iter().sum::<usize>() as f64 / neighbors.len() as f64
+                    };
+                    (key, idx)
+                })
+                .collect();
+
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            for (i, (_, idx)) in keyed.into_iter().enumerate() {
+                layer[i] = idx;
+                order.insert(idx, i);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_empty_graph() {
+        let mut graph = DependencyGraph::new();
+        compute_layout(&mut graph, &LayoutConfig::default());
+        assert_eq!(graph.node_count(), 0);
+    }
+}
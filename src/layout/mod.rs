@@ -0,0 +1,67 @@
+//! Layout module for computing node positions.
+//!
+//! This module assigns x/y coordinates to every node so that static
+//! exports and the web UI can render the graph without running a
+//! client-side layout.
+//!
+//! # Algorithm
+//!
+//! A layered (Sugiyama-style) layout flowing left to right:
+//!
+//! 1. Strongly connected components are collapsed so cycles share a layer
+//! 2. Each component is placed one layer after its furthest predecessor
+//! 3. Nodes within a layer are reordered with the barycenter heuristic
+//!    to reduce edge crossings
+//! 4. Layers are centered vertically
+//!
+//! The computed positions can be rendered directly with [`render_svg`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use sass_dep::graph::DependencyGraph;
+//! use sass_dep::layout::{compute_layout, LayoutConfig};
+//!
+//! let mut graph = DependencyGraph::new();
+//! // ... build graph ...
+//!
+//! compute_layout(&mut graph, &LayoutConfig::default());
+//! ```
+
+mod layered;
+mod svg;
+
+pub use layered::compute_layout;
+pub use svg::render_svg;
+
+use crate::graph::LabelStrategy;
+
+/// Configuration for the layout pass.
+#[derive(Debug, Clone)]
+pub struct LayoutConfig {
+    /// Width of a node box.
+    pub node_width: f64,
+    /// Height of a node box.
+    pub node_height: f64,
+    /// Horizontal space between layers.
+    pub layer_gap: f64,
+    /// Vertical space between nodes in the same layer.
+    pub node_gap: f64,
+    /// Number of barycenter sweeps used to reduce crossings.
+    pub iterations: usize,
+    /// How nodes are labeled when rendered.
+    pub label: LabelStrategy,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            node_width: 180.0,
+            node_height: 60.0,
+            layer_gap: 80.0,
+            node_gap: 20.0,
+            iterations: 4,
+            label: LabelStrategy::default(),
+        }
+    }
+}
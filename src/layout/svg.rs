@@ -0,0 +1,155 @@
+//! SVG rendering of a laid-out graph.
+//!
+//! This module renders the graph to a standalone SVG document using the
+//! positions computed by the layout pass, so a quick visual check does not
+//! require Graphviz or any other external tool.
+
+use std::fmt::Write;
+
+use super::LayoutConfig;
+use crate::graph::{DependencyGraph, DirectiveType, FileNode, NodeFlag};
+
+/// Margin around the drawing.
+const MARGIN: f64 = 20.0;
+
+/// Flags in the order they take precedence for coloring.
+///
+/// Matches the priority used by the web visualizer.
+const FLAG_PRIORITY: [NodeFlag; 6] = [
+    NodeFlag::EntryPoint,
+    NodeFlag::InCycle,
+    NodeFlag::Orphan,
+    NodeFlag::HighFanIn,
+    NodeFlag::HighFanOut,
+    NodeFlag::Leaf,
+];
+
+/// Renders the graph as an SVG document.
+///
+/// Nodes are colored by their primary flag and edges by directive type.
+/// Only nodes with a position are drawn, so [`compute_layout`] must run
+/// first with the same `config`.
+///
+/// [`compute_layout`]: super::compute_layout
+pub fn render_svg(graph: &DependencyGraph, config: &LayoutConfig) -> String {
+    let (width, height) = graph
+        .nodes()
+        .filter_map(|(_, node)| node.position)
+        .fold((0.0f64, 0.0f64), |(w, h), p| {
+            (w.max(p.x + config.node_width), h.max(p.y + config.node_height))
+        });
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+        w = width + 2.0 * MARGIN,
+        h = height + 2.0 * MARGIN,
+    );
+
+    // Arrow markers, one per directive type
+    svg.push_str("  <defs>\n");
+    for directive_type in [DirectiveType::Use, DirectiveType::Forward, DirectiveType::Import] {
+        let _ = writeln!(
+            svg,
+            r#"    <marker id="arrow-{t}" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="8" markerHeight="8" orient="auto"><path d="M 0 0 L 10 5 L 0 10 z" fill="{c}"/></marker>"#,
+            t = directive_type,
+            c = edge_color(directive_type),
+        );
+    }
+    svg.push_str("  </defs>\n");
+
+    // Edges first so nodes are drawn on top
+    for (from, to, edge) in graph.edges_with_nodes() {
+        let (Some(from), Some(to)) = (from.position, to.position) else {
+            continue;
+        };
+
+        let x1 = MARGIN + from.x + config.node_width;
+        let y1 = MARGIN + from.y + config.node_height / 2.0;
+        let x2 = MARGIN + to.x;
+        let y2 = MARGIN + to.y + config.node_height / 2.0;
+        let bend = ((x2 - x1).abs() / 2.0).max(config.layer_gap / 2.0);
+
+        let _ = writeln!(
+            svg,
+            r#"  <path d="M {x1} {y1} C {c1} {y1}, {c2} {y2}, {x2} {y2}" fill="none" stroke="{color}" stroke-width="1.5" marker-end="url(#arrow-{t})"/>"#,
+            c1 = x1 + bend,
+            c2 = x2 - bend,
+            color = edge_color(edge.directive_type),
+            t = edge.directive_type,
+        );
+    }
+
+    for (id, node) in graph.nodes() {
+        let Some(position) = node.position else {
+            continue;
+        };
+        let (fill, stroke) = node_colors(node);
+        let label = config.label.label(id, &node.metrics);
+
+        let _ = writeln!(
+            svg,
+            r##"  <g><title>{title}</title><rect x="{x}" y="{y}" width="{w}" height="{h}" rx="6" fill="{fill}" stroke="{stroke}" stroke-width="1.5"/><text x="{tx}" y="{ty}" text-anchor="middle" dominant-baseline="middle" fill="#1e293b">{label}</text></g>"##,
+            title = escape(id),
+            x = MARGIN + position.x,
+            y = MARGIN + position.y,
+            w = config.node_width,
+            h = config.node_height,
+            tx = MARGIN + position.x + config.node_width / 2.0,
+            ty = MARGIN + position.y + config.node_height / 2.0,
+            label = escape(&label),
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Returns the fill and stroke colors for a node based on its primary flag.
+fn node_colors(node: &FileNode) -> (&'static str, &'static str) {
+    match FLAG_PRIORITY.iter().find(|flag| node.has_flag(flag)) {
+        Some(NodeFlag::EntryPoint) => ("#dcfce7", "#22c55e"),
+        Some(NodeFlag::InCycle) => ("#fef3c7", "#f59e0b"),
+        Some(NodeFlag::Orphan) => ("#fee2e2", "#ef4444"),
+        Some(NodeFlag::HighFanIn) => ("#dbeafe", "#3b82f6"),
+        Some(NodeFlag::HighFanOut) => ("#f3e8ff", "#a855f7"),
+        Some(NodeFlag::Leaf) => ("#f1f5f9", "#94a3b8"),
+        None => ("#ffffff", "#e2e8f0"),
+    }
+}
+
+/// Returns the stroke color for an edge based on its directive type.
+fn edge_color(directive_type: DirectiveType) -> &'static str {
+    match directive_type {
+        DirectiveType::Use => "#3b82f6",
+        DirectiveType::Forward => "#22c55e",
+        DirectiveType::Import => "#f97316",
+    }
+}
+
+/// Escapes text for use in SVG content and attributes.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_empty_graph() {
+        let graph = DependencyGraph::new();
+        let svg = render_svg(&graph, &LayoutConfig::default());
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn escape_special_characters() {
+        assert_eq!(escape("a<b>&\"c\""), "a&lt;b&gt;&amp;&quot;c&quot;");
+    }
+}
@@ -15,8 +15,10 @@
 //! - [`resolver`] - Sass-compliant path resolution
 //! - [`graph`] - Dependency graph construction and representation
 //! - [`analyzer`] - Graph analysis (cycles, metrics, flags)
+//! - [`layout`] - Node positioning for rendering
 //! - [`output`] - JSON schema and serialization
 //! - [`web`] - Embedded web server for interactive visualization
+//! - [`synthetic`] - Synthetic project generation for benchmarks
 //!
 //! ## Example
 //!
@@ -37,9 +39,11 @@ pub mod analyzer;
 pub mod cli;
 pub mod commands;
 pub mod graph;
+pub mod layout;
 pub mod output;
 pub mod parser;
 pub mod resolver;
+pub mod synthetic;
 pub mod web;
 
 // Re-export commonly used types
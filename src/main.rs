@@ -4,8 +4,8 @@
 
 use anyhow::Result;
 use clap::Parser;
-use sass_dep::cli::{Cli, Commands};
-use sass_dep::commands::AnalyzeOptions;
+use sass_dep::cli::{Cli, Commands, OutputFormat};
+use sass_dep::commands::{AnalyzeOptions, CheckOptions, ErrorOutput, RmOptions};
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -16,21 +16,34 @@ fn main() -> Result<()> {
             output,
             format,
             include_orphans,
+            no_gitignore,
+            label,
             web,
             port,
         } => {
-            sass_dep::commands::analyze(AnalyzeOptions {
+            let result = sass_dep::commands::analyze(AnalyzeOptions {
                 root: &cli.root,
                 load_paths: &cli.load_paths,
+                auto_load_paths: cli.auto_load_paths,
                 entry_points: &entry_points,
                 output: output.as_deref(),
                 format,
                 include_orphans,
+                respect_gitignore: !no_gitignore,
+                label,
                 quiet: cli.quiet,
                 verbose: cli.verbose,
                 web,
                 port,
-            })?;
+            });
+
+            // Keep stdout parseable for JSON consumers
+            if let Err(err) = &result {
+                if format == OutputFormat::Json && !web {
+                    println!("{}", ErrorOutput::from_error(err).to_json()?);
+                }
+            }
+            result?;
         }
         Commands::Check {
             entry_points,
@@ -38,18 +51,23 @@ fn main() -> Result<()> {
             max_depth,
             max_fan_out,
             max_fan_in,
+            max_entry_reach,
+            changed_only,
         } => {
-            let violations = sass_dep::commands::check(
-                &cli.root,
-                &cli.load_paths,
-                &entry_points,
+            let violations = sass_dep::commands::check(CheckOptions {
+                root: &cli.root,
+                load_paths: &cli.load_paths,
+                auto_load_paths: cli.auto_load_paths,
+                entry_points: &entry_points,
                 no_cycles,
                 max_depth,
                 max_fan_out,
                 max_fan_in,
-                cli.quiet,
-                cli.verbose,
-            )?;
+                max_entry_reach: &max_entry_reach,
+                changed_only,
+                quiet: cli.quiet,
+                verbose: cli.verbose,
+            })?;
 
             if !violations.is_empty() {
                 std::process::exit(1);
@@ -61,6 +79,39 @@ fn main() -> Result<()> {
         } => {
             sass_dep::commands::export(&input, format)?;
         }
+        Commands::Rm {
+            file,
+            dry_run,
+            force,
+            fix,
+            no_gitignore,
+        } => {
+            sass_dep::commands::rm(RmOptions {
+                root: &cli.root,
+                load_paths: &cli.load_paths,
+                auto_load_paths: cli.auto_load_paths,
+                file: &file,
+                dry_run,
+                force,
+                fix,
+                respect_gitignore: !no_gitignore,
+                quiet: cli.quiet,
+                verbose: cli.verbose,
+            })?;
+        }
+        Commands::Serve {
+            projects,
+            port,
+        } => {
+            sass_dep::commands::serve(&projects, port)?;
+        }
+        Commands::Bench {
+            files,
+            fan_out,
+            iterations,
+        } => {
+            sass_dep::commands::bench(files, fan_out, iterations)?;
+        }
     }
 
     Ok(())
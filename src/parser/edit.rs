@@ -0,0 +1,119 @@
+//! Source editing for directives.
+//!
+//! This module removes directives from SCSS source using the locations
+//! reported by the parser, so tools can drop dangling `@use`, `@forward`,
+//! or `@import` statements without reformatting the rest of the file.
+
+use super::{Location, Parser};
+
+impl Parser {
+    /// Removes the directive starting at `location` from SCSS source.
+    ///
+    /// The directive extends to its terminating `;`, skipping strings and
+    /// parenthesized `with (...)` configuration. If nothing else is on its
+    /// lines, the lines are removed entirely.
+    ///
+    /// Returns `None` if no directive starts at `location` or it is not
+    /// terminated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sass_dep::parser::{Location, Parser};
+    ///
+    /// let source = "@use \"a\";\n@use \"b\";\n";
+    /// let edited = Parser::remove_directive(source, &Location::new(2, 1)).unwrap();
+    /// assert_eq!(edited, "@use \"a\";\n");
+    /// ```
+    pub fn remove_directive(input: &str, location: &Location) -> Option<String> {
+        let line_start = line_offset(input, location.line)?;
+        let start = line_start + location.column.checked_sub(1)?;
+        if !input.get(start..)?.starts_with('@') {
+            return None;
+        }
+
+        let end = start + statement_len(&input[start..])?;
+        let line_end = input[end..].find('\n').map(|i| end + i).unwrap_or(input.len());
+
+        let alone = input[line_start..start].trim().is_empty()
+            && input[end..line_end].trim().is_empty();
+
+        let (from, to) = if alone {
+            (line_start, (line_end + 1).min(input.len()))
+        } else {
+            (start, end)
+        };
+
+        Some(format!("{}{}", &input[..from], &input[to..]))
+    }
+}
+
+/// Returns the byte offset of the start of a 1-indexed line.
+fn line_offset(input: &str, line: usize) -> Option<usize> {
+    if line == 1 {
+        return Some(0);
+    }
+    input
+        .match_indices('\n')
+        .nth(line.checked_sub(2)?)
+        .map(|(i, _)| i + 1)
+}
+
+/// Returns the length of the statement up to and including its `;`.
+fn statement_len(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'"' | b'\'') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            b';' if depth == 0 => return Some(i + 1),
+            b'{' | b'}' if depth == 0 => return None,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_whole_line() {
+        let source = "@use \"a\";\n@use \"b\" as b;\n\n.x { color: red; }\n";
+        let edited = Parser::remove_directive(source, &Location::new(1, 1)).unwrap();
+        assert_eq!(edited, "@use \"b\" as b;\n\n.x { color: red; }\n");
+    }
+
+    #[test]
+    fn remove_configured_use() {
+        let source = "@use \"theme\" with (\n    $primary: blue,\n    $font: \"a;b\"\n);\n$x: 1;\n";
+        let edited = Parser::remove_directive(source, &Location::new(1, 1)).unwrap();
+        assert_eq!(edited, "$x: 1;\n");
+    }
+
+    #[test]
+    fn remove_inline_directive() {
+        let source = "$x: 1; @import \"old\";\n";
+        let edited = Parser::remove_directive(source, &Location::new(1, 8)).unwrap();
+        assert_eq!(edited, "$x: 1; \n");
+    }
+
+    #[test]
+    fn remove_invalid_location() {
+        assert!(Parser::remove_directive("@use \"a\";", &Location::new(1, 2)).is_none());
+        assert!(Parser::remove_directive("@use \"a\";", &Location::new(3, 1)).is_none());
+        assert!(Parser::remove_directive("@use \"a\"", &Location::new(1, 1)).is_none());
+    }
+}
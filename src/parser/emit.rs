@@ -0,0 +1,235 @@
+//! CSS output heuristic.
+//!
+//! This module estimates whether a stylesheet produces CSS on its own by
+//! scanning its top-level statements. Definitions (variables, mixins,
+//! functions, placeholders) and module directives are silent; style rules,
+//! top-level `@include`s, and other at-rules are assumed to emit CSS.
+
+use super::Parser;
+
+/// At-rules that never produce CSS output by themselves.
+const SILENT_AT_RULES: [&str; 9] = [
+    "use", "forward", "import", "charset", "debug", "warn", "error", "mixin", "function",
+];
+
+impl Parser {
+    /// Returns whether SCSS source likely produces CSS when compiled.
+    ///
+    /// This is a heuristic: anything that is not clearly a definition
+    /// counts as output, so it errs on the side of reporting CSS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sass_dep::parser::Parser;
+    ///
+    /// assert!(!Parser::emits_css("$primary: blue;\n@mixin center { display: flex; }"));
+    /// assert!(Parser::emits_css(".button { color: red; }"));
+    /// ```
+    pub fn emits_css(input: &str) -> bool {
+        let mut rest = input;
+
+        loop {
+            rest = skip_trivia(rest);
+            if rest.is_empty() {
+                return false;
+            }
+
+            let (statement, opens_block, after) = read_statement(rest);
+            let statement = statement.trim();
+
+            if !statement.is_empty() && !is_silent(statement) {
+                return true;
+            }
+
+            rest = if opens_block { skip_block(after) } else { after };
+        }
+    }
+}
+
+/// Returns whether a top-level statement is a definition without output.
+fn is_silent(statement: &str) -> bool {
+    if statement.starts_with('$') || statement.starts_with('%') {
+        return true;
+    }
+
+    let Some(at_rule) = statement.strip_prefix('@') else {
+        return false;
+    };
+    let name: String = at_rule
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '-')
+        .collect::<String>()
+        .to_lowercase();
+
+    // Plain CSS imports are passed through to the output
+    if name == "import"
+        && (at_rule.contains("url(") || at_rule.contains(".css") || at_rule.contains("//"))
+    {
+        return false;
+    }
+
+    SILENT_AT_RULES.contains(&name.as_str())
+}
+
+/// Skips whitespace and comments.
+fn skip_trivia(input: &str) -> &str {
+    let mut rest = input;
+    loop {
+        rest = rest.trim_start();
+        if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.find('\n').map(|i| &comment[i..]).unwrap_or("");
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.find("*/").map(|i| &comment[i + 2..]).unwrap_or("");
+        } else {
+            return rest;
+        }
+    }
+}
+
+/// Reads a statement up to `;`, `{`, or a stray `}`.
+///
+/// Returns the statement text, whether it opens a block, and the
+/// remaining input after the terminator.
+fn read_statement(input: &str) -> (&str, bool, &str) {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    let mut interpolation = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' | b'\'' => i = skip_string(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_multi_comment(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'/') && (i == 0 || bytes[i - 1] != b':') => {
+                i = skip_line_comment(bytes, i)
+            }
+            b'#' if bytes.get(i + 1) == Some(&b'{') => {
+                interpolation += 1;
+                i += 2;
+            }
+            b'}' if interpolation > 0 => {
+                interpolation -= 1;
+                i += 1;
+            }
+            b';' | b'}' if interpolation == 0 => return (&input[..i], false, &input[i + 1..]),
+            b'{' if interpolation == 0 => return (&input[..i], true, &input[i + 1..]),
+            _ => i += 1,
+        }
+    }
+
+    (input, false, "")
+}
+
+/// Skips the remainder of a block whose opening brace was consumed.
+fn skip_block(input: &str) -> &str {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    let mut depth = 1;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' | b'\'' => i = skip_string(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_multi_comment(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'/') && (i == 0 || bytes[i - 1] != b':') => {
+                i = skip_line_comment(bytes, i)
+            }
+            b'{' => {
+                depth += 1;
+                i += 1;
+            }
+            b'}' => {
+                depth -= 1;
+                i += 1;
+                if depth == 0 {
+                    return &input[i..];
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    ""
+}
+
+/// Returns the index after the string literal starting at `start`.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Returns the index after the `/* ... */` comment starting at `start`.
+fn skip_multi_comment(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 2;
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'*' && bytes[i + 1] == b'/' {
+            return i + 2;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Returns the index of the newline ending the `//` comment at `start`.
+fn skip_line_comment(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map(|p| start + p)
+        .unwrap_or(bytes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn definitions_are_silent() {
+        let input = r#"
+@use "sass:math";
+@forward "tokens";
+// .commented { color: red; }
+$spacing: 4px;
+$map: (small: 1px, large: 2px);
+@mixin card($pad: $spacing) {
+    .inner { padding: $pad; }
+}
+@function double($x) { @return $x * 2; }
+%placeholder { color: red; }
+"#;
+        assert!(!Parser::emits_css(input));
+    }
+
+    #[test]
+    fn style_rules_emit() {
+        assert!(Parser::emits_css("$x: 1;\n.button { color: red; }\n"));
+        assert!(Parser::emits_css(".icon-#{$name} { content: ''; }"));
+    }
+
+    #[test]
+    fn top_level_at_rules_emit() {
+        assert!(Parser::emits_css("@include reset;"));
+        assert!(Parser::emits_css("@media screen { .a { b: c; } }"));
+        assert!(Parser::emits_css("@font-face { font-family: x; }"));
+    }
+
+    #[test]
+    fn plain_css_imports_emit() {
+        assert!(Parser::emits_css("@import url(\"https://fonts.example.com/x.css\");"));
+        assert!(Parser::emits_css("@import \"theme.css\";"));
+        assert!(!Parser::emits_css("@import \"variables\";"));
+    }
+
+    #[test]
+    fn empty_input_is_silent() {
+        assert!(!Parser::emits_css(""));
+        assert!(!Parser::emits_css("/* only a comment */\n"));
+    }
+}
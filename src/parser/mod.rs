@@ -43,6 +43,8 @@
 //! ```
 
 mod directive;
+mod edit;
+mod emit;
 mod error;
 mod lexer;
 
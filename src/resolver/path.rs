@@ -30,6 +30,23 @@ impl Default for ResolverConfig {
     }
 }
 
+impl ResolverConfig {
+    /// Directories commonly used as load paths, relative to the project root.
+    pub const AUTO_LOAD_PATHS: &'static [&'static str] = &["node_modules", "styles", "src/styles"];
+
+    /// Detects conventional load path directories under a project root.
+    ///
+    /// Returns the absolute paths of the entries in
+    /// [`AUTO_LOAD_PATHS`](Self::AUTO_LOAD_PATHS) that exist as directories.
+    pub fn detect_load_paths(root: &Path) -> Vec<PathBuf> {
+        Self::AUTO_LOAD_PATHS
+            .iter()
+            .map(|dir| root.join(dir))
+            .filter(|path| path.is_dir())
+            .collect()
+    }
+}
+
 /// Errors that can occur during path resolution.
 #[derive(Debug, Error)]
 pub enum ResolveError {
@@ -382,6 +399,20 @@ mod tests {
         assert!(result.unwrap().ends_with("styles.scss"));
     }
 
+    #[test]
+    fn detect_load_paths_existing_only() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("node_modules")).unwrap();
+        fs::create_dir_all(temp.path().join("src/styles")).unwrap();
+
+        let detected = ResolverConfig::detect_load_paths(temp.path());
+
+        assert_eq!(
+            detected,
+            vec![temp.path().join("node_modules"), temp.path().join("src/styles")]
+        );
+    }
+
     #[test]
     fn resolve_from_directory_base() {
         let temp = TempDir::new().unwrap();
@@ -2,6 +2,12 @@
 //!
 //! This module provides a local HTTP server that serves the built
 //! React application and exposes the analysis data via a JSON API.
+//!
+//! A single server can host several named projects:
+//!
+//! - `GET /api/projects` lists the hosted projects
+//! - `GET /api/:project/data` returns a project's analysis
+//! - `GET /api/data` returns the first project's analysis
 
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -9,13 +15,15 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use axum::{
     body::Body,
-    extract::State,
+    extract::{Path, State},
     http::{header, Response, StatusCode},
     response::IntoResponse,
     routing::get,
     Json, Router,
 };
+use indexmap::IndexMap;
 use rust_embed::RustEmbed;
+use serde::Serialize;
 
 use crate::output::OutputSchema;
 
@@ -24,9 +32,21 @@ use crate::output::OutputSchema;
 #[folder = "web/dist/"]
 struct WebAssets;
 
+/// Project name used when serving a single analysis.
+const DEFAULT_PROJECT: &str = "default";
+
 /// Application state shared across request handlers.
 struct AppState {
-    data: OutputSchema,
+    projects: IndexMap<String, OutputSchema>,
+}
+
+/// Entry in the project listing.
+#[derive(Debug, Serialize)]
+struct ProjectSummary {
+    /// Project name, used in API paths.
+    name: String,
+    /// Path of the project's data endpoint.
+    data_url: String,
 }
 
 /// Starts the embedded web server and opens the browser.
@@ -42,10 +62,31 @@ struct AppState {
 /// - The server fails to bind to the specified port
 /// - The browser fails to open
 pub async fn serve(data: OutputSchema, port: u16) -> Result<()> {
-    let state = Arc::new(AppState { data });
+    let mut projects = IndexMap::new();
+    projects.insert(DEFAULT_PROJECT.to_string(), data);
+    serve_projects(projects, port).await
+}
+
+/// Starts the embedded web server hosting several named projects.
+///
+/// Each project's analysis is served at `/api/:project/data`, and the
+/// UI selects one with the `?project=` query parameter.
+///
+/// # Arguments
+///
+/// * `projects` - Analysis outputs keyed by project name
+/// * `port` - The port to listen on
+///
+/// # Errors
+///
+/// Returns an error if the server fails to bind to the specified port.
+pub async fn serve_projects(projects: IndexMap<String, OutputSchema>, port: u16) -> Result<()> {
+    let state = Arc::new(AppState { projects });
 
     let app = Router::new()
         .route("/api/data", get(api_data))
+        .route("/api/projects", get(api_projects))
+        .route("/api/:project/data", get(api_project_data))
         .fallback(static_handler)
         .with_state(state);
 
@@ -74,8 +115,41 @@ pub async fn serve(data: OutputSchema, port: u16) -> Result<()> {
 }
 
 /// Handler for the API data endpoint.
-async fn api_data(State(state): State<Arc<AppState>>) -> Json<OutputSchema> {
-    Json(state.data.clone())
+///
+/// Returns the first hosted project.
+async fn api_data(State(state): State<Arc<AppState>>) -> Result<Json<OutputSchema>, StatusCode> {
+    state
+        .projects
+        .values()
+        .next()
+        .map(|data| Json(data.clone()))
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Handler listing the hosted projects.
+async fn api_projects(State(state): State<Arc<AppState>>) -> Json<Vec<ProjectSummary>> {
+    Json(
+        state
+            .projects
+            .keys()
+            .map(|name| ProjectSummary {
+                name: name.clone(),
+                data_url: format!("/api/{}/data", name),
+            })
+            .collect(),
+    )
+}
+
+/// Handler for a single project's data.
+async fn api_project_data(
+    State(state): State<Arc<AppState>>,
+    Path(project): Path<String>,
+) -> Result<Json<OutputSchema>, StatusCode> {
+    state
+        .projects
+        .get(&project)
+        .map(|data| Json(data.clone()))
+        .ok_or(StatusCode::NOT_FOUND)
 }
 
 /// Handler for serving static files from embedded assets.
@@ -112,3 +186,44 @@ async fn static_handler(uri: axum::http::Uri) -> impl IntoResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyGraph;
+
+    fn state(names: &[&str]) -> Arc<AppState> {
+        let graph = DependencyGraph::new();
+        let projects = names
+            .iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    OutputSchema::from_graph(&graph, std::path::Path::new(name)),
+                )
+            })
+            .collect();
+        Arc::new(AppState { projects })
+    }
+
+    #[tokio::test]
+    async fn project_routes() {
+        let state = state(&["app", "admin"]);
+
+        let Json(projects) = api_projects(State(state.clone())).await;
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["app", "admin"]);
+        assert_eq!(projects[1].data_url, "/api/admin/data");
+
+        assert!(api_project_data(State(state.clone()), Path("admin".to_string()))
+            .await
+            .is_ok());
+        assert_eq!(
+            api_project_data(State(state.clone()), Path("missing".to_string()))
+                .await
+                .unwrap_err(),
+            StatusCode::NOT_FOUND
+        );
+        assert!(api_data(State(state)).await.is_ok());
+    }
+}
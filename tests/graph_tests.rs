@@ -209,7 +209,7 @@ fn discover_orphans() {
     assert!(graph.get_node("_orphan.scss").is_none());
 
     // Discover orphans
-    graph.discover_orphans(&root, &resolver).unwrap();
+    graph.discover_orphans(&root, &resolver, true).unwrap();
 
     // After discovering orphans
     assert_eq!(graph.node_count(), 4);
@@ -276,3 +276,274 @@ fn graph_node_iteration_order() {
     // Order should be deterministic
     assert_eq!(ids1, ids2);
 }
+
+#[test]
+fn update_file_replaces_edges() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_simple_project(&root);
+    fs::write(root.join("_extra.scss"), "$extra: 1;\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+
+    let dirty = graph
+        .update_file(
+            &root.join("_mixins.scss"),
+            "@use \"extra\";\n",
+            &resolver,
+            &root,
+        )
+        .unwrap();
+
+    // mixins no longer uses variables, but now uses the new extra partial
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.edge_count(), 3);
+    let edges: Vec<_> = graph.edges().map(|(from, to, _)| (from, to)).collect();
+    assert!(edges.contains(&("_mixins.scss", "_extra.scss")));
+    assert!(!edges.contains(&("_mixins.scss", "_variables.scss")));
+
+    for id in ["main.scss", "_mixins.scss", "_variables.scss", "_extra.scss"] {
+        assert!(dirty.contains(id), "{} should be dirty", id);
+    }
+}
+
+#[test]
+fn update_file_recomputes_orphans() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_simple_project(&root);
+    fs::write(root.join("_extra.scss"), "$extra: 1;\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+    graph.discover_orphans(&root, &resolver, true).unwrap();
+    assert!(graph
+        .get_node("_extra.scss")
+        .unwrap()
+        .has_flag(&NodeFlag::Orphan));
+
+    // Importing the orphan makes it reachable
+    graph
+        .update_file(
+            &root.join("_mixins.scss"),
+            "@use \"variables\";\n@use \"extra\";\n",
+            &resolver,
+            &root,
+        )
+        .unwrap();
+    assert!(!graph
+        .get_node("_extra.scss")
+        .unwrap()
+        .has_flag(&NodeFlag::Orphan));
+
+    // Dropping main's use of mixins leaves mixins and extra without a path
+    // from the entry point
+    graph
+        .update_file(
+            &root.join("main.scss"),
+            "@use \"variables\";\n",
+            &resolver,
+            &root,
+        )
+        .unwrap();
+    for id in ["_mixins.scss", "_extra.scss"] {
+        assert!(
+            graph.get_node(id).unwrap().has_flag(&NodeFlag::Orphan),
+            "{} should be orphan",
+            id
+        );
+    }
+    for id in ["main.scss", "_variables.scss"] {
+        assert!(
+            !graph.get_node(id).unwrap().has_flag(&NodeFlag::Orphan),
+            "{} should be reachable",
+            id
+        );
+    }
+}
+
+#[test]
+fn update_file_unknown_file() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_simple_project(&root);
+    fs::write(root.join("_unrelated.scss"), "").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+
+    let result = graph.update_file(&root.join("_unrelated.scss"), "", &resolver, &root);
+    assert!(result.is_err());
+}
+
+#[test]
+fn discover_orphans_respects_gitignore() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_simple_project(&root);
+
+    fs::create_dir_all(root.join("dist")).unwrap();
+    fs::write(root.join("dist/_generated.scss"), "").unwrap();
+    fs::write(root.join(".gitignore"), "dist/\n").unwrap();
+
+    let resolver = Resolver::default();
+
+    let mut graph = DependencyGraph::new();
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+    graph.discover_orphans(&root, &resolver, true).unwrap();
+    assert!(graph.get_node("dist/_generated.scss").is_none());
+
+    let mut graph = DependencyGraph::new();
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+    graph.discover_orphans(&root, &resolver, false).unwrap();
+    assert!(graph.get_node("dist/_generated.scss").is_some());
+}
+
+#[test]
+fn namespace_shadowing_recorded() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(
+        root.join("main.scss"),
+        "@use \"sass:math\";\n@use \"helpers\" as math;\n",
+    )
+    .unwrap();
+    fs::write(root.join("_helpers.scss"), "@use \"sass:map\";\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+
+    let shadowing = graph.namespace_shadowing();
+    assert_eq!(shadowing.len(), 1);
+    assert_eq!(shadowing[0].file, "main.scss");
+    assert_eq!(shadowing[0].namespace, "math");
+}
+
+#[test]
+fn graph_edges_with_nodes() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_simple_project(&root);
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+
+    let typed: Vec<_> = graph
+        .edges_with_nodes()
+        .map(|(from, to, _)| (from.id.as_str(), to.id.as_str()))
+        .collect();
+    let by_id: Vec<_> = graph.edges().map(|(from, to, _)| (from, to)).collect();
+
+    assert_eq!(typed.len(), 3);
+    assert_eq!(typed, by_id);
+
+    let (from, _, _) = graph.edges_with_nodes().next().unwrap();
+    assert!(from.has_flag(&NodeFlag::EntryPoint));
+}
+
+#[test]
+fn simulate_edge_changes() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_cycle_project(&root);
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&root.join("_a.scss"), &resolver, &root)
+        .unwrap();
+
+    // Removing c -> a breaks the a -> b -> c -> a cycle
+    let report = graph.simulate(&[("_c.scss", "_a.scss")], &[]).unwrap();
+    assert_eq!(
+        report.cycles_broken,
+        vec![vec![
+            "_a.scss".to_string(),
+            "_b.scss".to_string(),
+            "_c.scss".to_string()
+        ]]
+    );
+    assert!(report.cycles_created.is_empty());
+
+    let a = report
+        .metric_changes
+        .iter()
+        .find(|c| c.file == "_a.scss")
+        .unwrap();
+    assert_eq!(a.before.fan_in, 1);
+    assert_eq!(a.after.fan_in, 0);
+
+    // The graph itself is untouched
+    assert_eq!(graph.edge_count(), 3);
+
+    // Unknown edges are rejected
+    assert!(graph.simulate(&[("_a.scss", "_c.scss")], &[]).is_err());
+}
+
+#[test]
+fn simulate_creates_cycle() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_simple_project(&root);
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+
+    let report = graph
+        .simulate(&[], &[("_variables.scss", "_mixins.scss")])
+        .unwrap();
+
+    assert_eq!(report.cycles_created.len(), 1);
+    assert!(report.cycles_broken.is_empty());
+    assert_eq!(graph.edge_count(), 3);
+}
+
+#[test]
+fn build_cycle_behind_entry() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+    create_cycle_project(&root);
+    fs::write(root.join("main.scss"), "@use \"a\";\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    // The cycle does not pass through the entry point
+    graph
+        .build_from_entry(&root.join("main.scss"), &resolver, &root)
+        .unwrap();
+
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.edge_count(), 4);
+}
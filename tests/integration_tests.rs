@@ -1,12 +1,22 @@
 //! End-to-end integration tests.
 
 use std::fs;
-use std::path::Path;
-
-use sass_dep::analyzer::Analyzer;
-use sass_dep::graph::{DependencyGraph, NodeFlag};
+use std::path::{Path, PathBuf};
+
+use globset::Glob;
+use petgraph::graph::{DiGraph, NodeIndex};
+use sass_dep::analyzer::{
+    calculate_entry_reachability, compute_metrics, Analyzer, DependencyGraphLike,
+};
+use sass_dep::cli::{EntryReachRule, OutputFormat};
+use sass_dep::commands::{
+    analyze, check, rm, AnalyzeOptions, CheckOptions, ErrorOutput, RmOptions, Violation,
+};
+use sass_dep::graph::{DependencyGraph, LabelStrategy, NodeFlag};
+use sass_dep::layout::{compute_layout, render_svg, LayoutConfig};
 use sass_dep::output::{OutputSchema, Serializer};
 use sass_dep::resolver::Resolver;
+use sass_dep::synthetic::{generate, SyntheticConfig};
 use tempfile::TempDir;
 
 /// Tests the full analysis pipeline on the simple fixture.
@@ -69,6 +79,30 @@ fn analyze_cycles_fixture() {
             id
         );
     }
+
+    // Every edge lies on the cycle
+    for (from, to, edge) in graph.edges() {
+        assert_eq!(edge.cycle_index, Some(0), "Edge {} -> {} should be in cycle", from, to);
+    }
+}
+
+/// Tests that edges outside cycles are not marked.
+#[test]
+fn acyclic_edges_not_marked() {
+    let fixture_path = Path::new("tests/fixtures/simple").canonicalize().unwrap();
+    let entry = fixture_path.join("main.scss");
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph
+        .build_from_entry(&entry, &resolver, &fixture_path)
+        .unwrap();
+
+    Analyzer::default().analyze(&mut graph);
+
+    assert!(graph.edge_count() > 0);
+    assert!(graph.edges().all(|(_, _, edge)| !edge.in_cycle()));
 }
 
 /// Tests legacy @import handling.
@@ -355,3 +389,751 @@ fn high_fan_in_flag() {
     assert_eq!(shared.metrics.fan_in, 6);
     assert!(shared.has_flag(&NodeFlag::HighFanIn));
 }
+
+/// Tests detection of entry points reachable from other entry points.
+#[test]
+fn redundant_entry_detection() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    // app uses theme, which is also passed as its own entry point
+    fs::write(root.join("app.scss"), "@use \"theme\";\n").unwrap();
+    fs::write(root.join("theme.scss"), "@use \"shared\";\n").unwrap();
+    fs::write(root.join("admin.scss"), "@use \"shared\";\n").unwrap();
+    fs::write(root.join("_shared.scss"), "$shared: 1;\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    for entry in ["app.scss", "theme.scss", "admin.scss"] {
+        graph.build_from_entry(&root.join(entry), &resolver, &root).unwrap();
+    }
+    Analyzer::default().analyze(&mut graph);
+
+    let redundant = graph.redundant_entries();
+    assert_eq!(redundant.len(), 1);
+    assert_eq!(redundant[0].entry, "theme.scss");
+    assert_eq!(redundant[0].reachable_from, vec!["app.scss".to_string()]);
+}
+
+/// Tests the layered layout pass.
+#[test]
+fn layout_positions() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    // a -> b -> d, a -> c -> d
+    fs::write(root.join("a.scss"), "@use \"b\";\n@use \"c\";\n").unwrap();
+    fs::write(root.join("_b.scss"), "@use \"d\";\n").unwrap();
+    fs::write(root.join("_c.scss"), "@use \"d\";\n").unwrap();
+    fs::write(root.join("_d.scss"), "$d: 1;\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph.build_from_entry(&root.join("a.scss"), &resolver, &root).unwrap();
+    compute_layout(&mut graph, &LayoutConfig::default());
+
+    let pos = |id: &str| graph.get_node(id).unwrap().position.unwrap();
+
+    // Dependencies are laid out to the right of their dependents
+    assert!(pos("a.scss").x < pos("_b.scss").x);
+    assert_eq!(pos("_b.scss").x, pos("_c.scss").x);
+    assert!(pos("_b.scss").x < pos("_d.scss").x);
+
+    // Nodes in the same layer do not overlap
+    assert_ne!(pos("_b.scss").y, pos("_c.scss").y);
+}
+
+/// Tests native SVG rendering.
+#[test]
+fn render_svg_output() {
+    let fixture_path = Path::new("tests/fixtures/simple").canonicalize().unwrap();
+    let entry = fixture_path.join("main.scss");
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph.build_from_entry(&entry, &resolver, &fixture_path).unwrap();
+    Analyzer::default().analyze(&mut graph);
+
+    let config = LayoutConfig::default();
+    compute_layout(&mut graph, &config);
+    let svg = render_svg(&graph, &config);
+
+    assert!(svg.starts_with("<svg"));
+    assert_eq!(svg.matches("<rect").count(), 3);
+    assert_eq!(svg.matches("marker-end=").count(), 3);
+    assert!(svg.contains("#dcfce7")); // entry point color
+
+    // Custom label templates
+    let config = LayoutConfig {
+        label: "{basename}: {fan_out}".parse::<LabelStrategy>().unwrap(),
+        ..LayoutConfig::default()
+    };
+    let svg = render_svg(&graph, &config);
+    assert!(svg.contains(">main.scss: 2</text>"));
+}
+
+/// Tests per-entry reachability and the entry reach check rule.
+#[test]
+fn entry_reach_check() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::create_dir_all(root.join("admin")).unwrap();
+    fs::write(root.join("app.scss"), "@use \"admin/panel\";\n").unwrap();
+    fs::write(root.join("admin.scss"), "@use \"admin/panel\";\n").unwrap();
+    fs::write(root.join("admin/_panel.scss"), "$panel: 1;\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+    for entry in ["app.scss", "admin.scss"] {
+        graph.build_from_entry(&root.join(entry), &resolver, &root).unwrap();
+    }
+
+    let reachability = calculate_entry_reachability(&graph);
+    assert_eq!(
+        reachability["admin/_panel.scss"],
+        vec!["admin.scss".to_string(), "app.scss".to_string()]
+    );
+    assert_eq!(reachability["app.scss"], vec!["app.scss".to_string()]);
+
+    let entry_points = vec![PathBuf::from("app.scss"), PathBuf::from("admin.scss")];
+    let rules = vec![EntryReachRule {
+        glob: Glob::new("admin/**").unwrap(),
+        max: 1,
+    }];
+    let violations = check(CheckOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        entry_points: &entry_points,
+        no_cycles: false,
+        max_depth: None,
+        max_fan_out: None,
+        max_fan_in: None,
+        max_entry_reach: &rules,
+        changed_only: false,
+        quiet: true,
+        verbose: 0,
+    })
+    .unwrap();
+
+    assert_eq!(violations.len(), 1);
+    assert!(matches!(
+        &violations[0],
+        Violation::MaxEntryReach { file, .. } if file == "admin/_panel.scss"
+    ));
+}
+
+/// Tests structured errors for failed runs.
+#[test]
+fn structured_error_output() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    let entry_points = vec![PathBuf::from("missing.scss")];
+    let err = analyze(AnalyzeOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        entry_points: &entry_points,
+        output: None,
+        format: OutputFormat::Json,
+        include_orphans: false,
+        respect_gitignore: true,
+        label: LabelStrategy::default(),
+        quiet: true,
+        verbose: 0,
+        web: false,
+        port: 3000,
+    })
+    .unwrap_err();
+
+    let json = ErrorOutput::from_error(&err).to_json().unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed["error"]["code"], "file_not_found");
+    assert!(parsed["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("missing.scss"));
+    assert!(!parsed["error"]["details"].as_array().unwrap().is_empty());
+}
+
+/// Tests that a failed JSON run prints the structured error to stdout.
+#[test]
+fn structured_error_on_stdout() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_sass-dep"))
+        .arg("--root")
+        .arg(&root)
+        .args(["analyze", "missing.scss"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["error"]["code"], "file_not_found");
+    assert!(parsed["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("missing.scss"));
+}
+
+/// Tests detection of files sharing a module name.
+#[test]
+fn duplicate_basename_detection() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::create_dir_all(root.join("base")).unwrap();
+    fs::create_dir_all(root.join("theme")).unwrap();
+    fs::write(
+        root.join("main.scss"),
+        "@use \"base/variables\" as base;\n@use \"theme\";\n",
+    )
+    .unwrap();
+    fs::write(root.join("base/_variables.scss"), "$a: 1;\n").unwrap();
+    fs::write(root.join("theme/_index.scss"), "@forward \"variables\";\n").unwrap();
+    fs::write(root.join("theme/variables.scss"), "$b: 2;\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    graph.build_from_entry(&root.join("main.scss"), &resolver, &root).unwrap();
+    Analyzer::default().analyze(&mut graph);
+
+    let duplicates = graph.duplicate_basenames();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].basename, "variables");
+    assert_eq!(duplicates[0].files.len(), 2);
+    assert_eq!(duplicates[0].files[0].file, "base/_variables.scss");
+    assert_eq!(duplicates[0].files[0].imported_by, vec!["main.scss".to_string()]);
+    assert_eq!(duplicates[0].files[1].file, "theme/variables.scss");
+    assert_eq!(
+        duplicates[0].files[1].imported_by,
+        vec!["theme/_index.scss".to_string()]
+    );
+}
+
+/// Tests that check warns about shared module names at default verbosity.
+#[test]
+fn duplicate_basename_warning() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::create_dir_all(root.join("base")).unwrap();
+    fs::create_dir_all(root.join("theme")).unwrap();
+    fs::write(
+        root.join("main.scss"),
+        "@use \"base/variables\" as base;\n@use \"theme/variables\" as theme;\n",
+    )
+    .unwrap();
+    fs::write(root.join("base/_variables.scss"), "$a: 1;\n").unwrap();
+    fs::write(root.join("theme/_variables.scss"), "$b: 2;\n").unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_sass-dep"))
+        .arg("--root")
+        .arg(&root)
+        .args(["check", "main.scss"])
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("2 files share the module name 'variables'"));
+}
+
+/// Tests detection of entry points without CSS output.
+#[test]
+fn dead_entry_detection() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(root.join("tokens.scss"), "@use \"variables\";\n").unwrap();
+    fs::write(root.join("app.scss"), "@use \"variables\";\n@use \"reset\";\n").unwrap();
+    fs::write(root.join("_variables.scss"), "$primary: blue;\n").unwrap();
+    fs::write(root.join("_reset.scss"), "* { margin: 0; }\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    for entry in ["tokens.scss", "app.scss"] {
+        graph.build_from_entry(&root.join(entry), &resolver, &root).unwrap();
+    }
+    Analyzer::default().analyze(&mut graph);
+
+    assert!(graph.get_node("_reset.scss").unwrap().emits_css);
+    assert!(!graph.get_node("_variables.scss").unwrap().emits_css);
+    assert_eq!(graph.dead_entries(), ["tokens.scss".to_string()]);
+}
+
+/// Tests that entries loading unresolved files are not reported as dead.
+#[test]
+fn dead_entry_unresolved_import() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(root.join("main.scss"), "@import \"~bootstrap/scss/bootstrap\";\n").unwrap();
+    fs::write(root.join("theme.scss"), "@use \"vendor\";\n").unwrap();
+    fs::write(root.join("_vendor.scss"), "@use \"pkg:tokens\";\n").unwrap();
+    fs::write(root.join("tokens.scss"), "@use \"sass:math\";\n").unwrap();
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+
+    for entry in ["main.scss", "theme.scss", "tokens.scss"] {
+        graph.build_from_entry(&root.join(entry), &resolver, &root).unwrap();
+    }
+    Analyzer::default().analyze(&mut graph);
+
+    assert!(graph.get_node("main.scss").unwrap().has_unresolved);
+    assert!(graph.get_node("_vendor.scss").unwrap().has_unresolved);
+    assert!(!graph.get_node("tokens.scss").unwrap().has_unresolved);
+    assert_eq!(graph.dead_entries(), ["tokens.scss".to_string()]);
+}
+
+/// Runs git in a test repository.
+fn git(root: &Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(root)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Tests that --changed-only limits checks to affected files.
+#[test]
+fn check_changed_only() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(root.join("main.scss"), "@use \"a\";\n@use \"c\";\n").unwrap();
+    fs::write(root.join("_a.scss"), "@use \"b\";\n").unwrap();
+    fs::write(root.join("_b.scss"), "@use \"a\";\n").unwrap();
+    fs::write(root.join("_c.scss"), "$c: 1;\n").unwrap();
+
+    git(&root, &["init", "-q"]);
+    git(&root, &["add", "."]);
+    git(&root, &["commit", "-q", "-m", "init"]);
+
+    let entry_points = vec![PathBuf::from("main.scss")];
+    let run = |changed_only| {
+        check(CheckOptions {
+            root: &root,
+            load_paths: &[],
+            auto_load_paths: false,
+            entry_points: &entry_points,
+            no_cycles: true,
+            max_depth: None,
+            max_fan_out: None,
+            max_fan_in: None,
+            max_entry_reach: &[],
+            changed_only,
+            quiet: true,
+            verbose: 0,
+        })
+        .unwrap()
+    };
+
+    assert_eq!(run(false).len(), 1);
+
+    // Changing a file outside the cycle leaves it out of scope
+    fs::write(root.join("_c.scss"), "$c: 2;\n").unwrap();
+    assert!(run(true).is_empty());
+
+    // Changing a cycle member brings the cycle back into scope
+    fs::write(root.join("_b.scss"), "@use \"a\";\n$b: 1;\n").unwrap();
+    assert_eq!(run(true).len(), 1);
+}
+
+/// Tests that --changed-only keeps changed files with non-ASCII names in scope.
+#[test]
+fn check_changed_only_non_ascii_path() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(root.join("main.scss"), "@use \"café\";\n@use \"a\";\n").unwrap();
+    fs::write(root.join("_a.scss"), "@use \"café\";\n").unwrap();
+    fs::write(root.join("_café.scss"), "$c: 1;\n").unwrap();
+
+    git(&root, &["init", "-q"]);
+    git(&root, &["add", "."]);
+    git(&root, &["commit", "-q", "-m", "init"]);
+
+    fs::write(root.join("_café.scss"), "$c: 2;\n").unwrap();
+
+    let entry_points = vec![PathBuf::from("main.scss")];
+    let violations = check(CheckOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        entry_points: &entry_points,
+        no_cycles: false,
+        max_depth: None,
+        max_fan_out: None,
+        max_fan_in: Some(1),
+        max_entry_reach: &[],
+        changed_only: true,
+        quiet: true,
+        verbose: 0,
+    })
+    .unwrap();
+
+    assert_eq!(violations.len(), 1);
+    assert!(matches!(&violations[0], Violation::MaxFanIn { file, .. } if file == "_café.scss"));
+}
+
+/// Tests that --changed-only fails cleanly outside a git working tree.
+#[test]
+fn check_changed_only_requires_git() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(root.join("main.scss"), "$a: 1;\n").unwrap();
+
+    let entry_points = vec![PathBuf::from("main.scss")];
+    let err = check(CheckOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        entry_points: &entry_points,
+        no_cycles: true,
+        max_depth: None,
+        max_fan_out: None,
+        max_fan_in: None,
+        max_entry_reach: &[],
+        changed_only: true,
+        quiet: true,
+        verbose: 0,
+    })
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "--changed-only requires a git working tree");
+}
+
+/// Tests that --changed-only follows added edges into the subtree below.
+#[test]
+fn check_changed_only_added_edge() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::create_dir_all(root.join("admin")).unwrap();
+    fs::write(root.join("app.scss"), "$app: 1;\n").unwrap();
+    fs::write(root.join("admin.scss"), "@use \"admin/panel\";\n").unwrap();
+    fs::write(root.join("admin/_panel.scss"), "@use \"internal\";\n").unwrap();
+    fs::write(root.join("admin/_internal.scss"), "$internal: 1;\n").unwrap();
+
+    git(&root, &["init", "-q"]);
+    git(&root, &["add", "."]);
+    git(&root, &["commit", "-q", "-m", "init"]);
+
+    fs::write(root.join("app.scss"), "@use \"admin/panel\";\n").unwrap();
+
+    let entry_points = vec![PathBuf::from("app.scss"), PathBuf::from("admin.scss")];
+    let max_entry_reach = vec![EntryReachRule {
+        glob: Glob::new("admin/**").unwrap(),
+        max: 1,
+    }];
+    let run = |changed_only| {
+        let mut files: Vec<String> = check(CheckOptions {
+            root: &root,
+            load_paths: &[],
+            auto_load_paths: false,
+            entry_points: &entry_points,
+            no_cycles: false,
+            max_depth: None,
+            max_fan_out: None,
+            max_fan_in: None,
+            max_entry_reach: &max_entry_reach,
+            changed_only,
+            quiet: true,
+            verbose: 0,
+        })
+        .unwrap()
+        .into_iter()
+        .map(|v| match v {
+            Violation::MaxEntryReach { file, .. } => file,
+            other => panic!("unexpected violation: {:?}", other),
+        })
+        .collect();
+        files.sort();
+        files
+    };
+
+    let expected = vec!["admin/_internal.scss", "admin/_panel.scss"];
+    assert_eq!(run(false), expected);
+    assert_eq!(run(true), expected);
+}
+
+/// Tests that --changed-only follows removed edges into the subtree below.
+#[test]
+fn check_changed_only_removed_edge() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(root.join("main.scss"), "@use \"x\";\n@use \"a\";\n").unwrap();
+    fs::write(root.join("_x.scss"), "@use \"c\";\n").unwrap();
+    fs::write(root.join("_a.scss"), "@use \"b\";\n").unwrap();
+    fs::write(root.join("_b.scss"), "@use \"c\";\n").unwrap();
+    fs::write(root.join("_c.scss"), "@use \"d\";\n").unwrap();
+    fs::write(root.join("_d.scss"), "$d: 1;\n").unwrap();
+
+    git(&root, &["init", "-q"]);
+    git(&root, &["add", "."]);
+    git(&root, &["commit", "-q", "-m", "init"]);
+
+    // Dropping the short path pushes d from depth 3 to depth 4
+    fs::write(root.join("_x.scss"), "$x: 1;\n").unwrap();
+
+    let entry_points = vec![PathBuf::from("main.scss")];
+    let violations = check(CheckOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        entry_points: &entry_points,
+        no_cycles: false,
+        max_depth: Some(3),
+        max_fan_out: None,
+        max_fan_in: None,
+        max_entry_reach: &[],
+        changed_only: true,
+        quiet: true,
+        verbose: 0,
+    })
+    .unwrap();
+
+    assert_eq!(violations.len(), 1);
+    assert!(matches!(&violations[0], Violation::MaxDepth { file, depth: 4, .. } if file == "_d.scss"));
+}
+
+/// Tests that synthetic projects build into the expected graph.
+#[test]
+fn synthetic_project_builds() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    let config = SyntheticConfig {
+        files: 120,
+        fan_out: 3,
+        files_per_dir: 25,
+        seed: 42,
+    };
+    let project = generate(&root, &config).unwrap();
+    assert_eq!(project.files.len(), 121);
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+    graph.build_from_entry(&project.entry, &resolver, &root).unwrap();
+    Analyzer::default().analyze(&mut graph);
+
+    // Every partial is reachable and the graph is acyclic
+    assert_eq!(graph.node_count(), 121);
+    assert_eq!(graph.edge_count(), project.dependencies);
+    assert!(graph.get_cycles().is_empty());
+
+    // Same seed, same project
+    let again = TempDir::new().unwrap();
+    let other = generate(again.path(), &config).unwrap();
+    assert_eq!(other.dependencies, project.dependencies);
+}
+
+/// Tests safe deletion with dependents.
+#[test]
+fn rm_with_dependents() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(
+        root.join("main.scss"),
+        "@use \"tokens\";\n@use \"legacy\" with (\n    $x: 1\n);\n.a { b: c; }\n",
+    )
+    .unwrap();
+    fs::write(root.join("_other.scss"), "@forward \"legacy\";\n").unwrap();
+    fs::write(root.join("_legacy.scss"), "$x: 0 !default;\n").unwrap();
+    fs::write(root.join("_tokens.scss"), "$t: 1;\n").unwrap();
+
+    let file = PathBuf::from("_legacy.scss");
+    let opts = |dry_run, force, fix| RmOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        file: &file,
+        dry_run,
+        force,
+        fix,
+        respect_gitignore: true,
+        quiet: true,
+        verbose: 0,
+    };
+
+    // Dry runs and unforced removals leave everything in place
+    rm(opts(true, true, true)).unwrap();
+    assert!(rm(opts(false, false, false)).is_err());
+    assert!(root.join("_legacy.scss").exists());
+
+    rm(opts(false, true, true)).unwrap();
+    assert!(!root.join("_legacy.scss").exists());
+    assert_eq!(
+        fs::read_to_string(root.join("main.scss")).unwrap(),
+        "@use \"tokens\";\n.a { b: c; }\n"
+    );
+    assert_eq!(fs::read_to_string(root.join("_other.scss")).unwrap(), "");
+}
+
+/// Tests that --fix keeps everything when a directive cannot be removed.
+#[test]
+fn rm_fix_multi_path_import() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    let main = "@use \"tokens\";\n@import \"a\", \"legacy\";\n";
+    fs::write(root.join("main.scss"), main).unwrap();
+    fs::write(root.join("_other.scss"), "@forward \"legacy\";\n").unwrap();
+    fs::write(root.join("_legacy.scss"), "$x: 0 !default;\n").unwrap();
+    fs::write(root.join("_tokens.scss"), "$t: 1;\n").unwrap();
+    fs::write(root.join("_a.scss"), "$a: 1;\n").unwrap();
+
+    let file = PathBuf::from("_legacy.scss");
+    let err = rm(RmOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        file: &file,
+        dry_run: false,
+        force: true,
+        fix: true,
+        respect_gitignore: true,
+        quiet: true,
+        verbose: 0,
+    })
+    .unwrap_err();
+
+    assert!(err.to_string().contains("main.scss:2:1"));
+    assert!(root.join("_legacy.scss").exists());
+    assert_eq!(fs::read_to_string(root.join("main.scss")).unwrap(), main);
+    assert_eq!(
+        fs::read_to_string(root.join("_other.scss")).unwrap(),
+        "@forward \"legacy\";\n"
+    );
+}
+
+/// Tests deleting a file without dependents.
+#[test]
+fn rm_unused_file() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    fs::write(root.join("main.scss"), "$x: 1;\n").unwrap();
+    fs::write(root.join("_unused.scss"), "$y: 1;\n").unwrap();
+
+    let file = PathBuf::from("_unused.scss");
+    rm(RmOptions {
+        root: &root,
+        load_paths: &[],
+        auto_load_paths: false,
+        file: &file,
+        dry_run: false,
+        force: false,
+        fix: false,
+        respect_gitignore: true,
+        quiet: true,
+        verbose: 0,
+    })
+    .unwrap();
+
+    assert!(!root.join("_unused.scss").exists());
+    assert!(root.join("main.scss").exists());
+}
+
+/// Tests that analyzing through the graph trait matches in-place analysis.
+#[test]
+fn analyze_graph_matches_analyze() {
+    let fixture_path = Path::new("tests/fixtures/complex").canonicalize().unwrap();
+    let entry = fixture_path.join("main.scss");
+
+    let resolver = Resolver::default();
+    let mut graph = DependencyGraph::new();
+    graph.build_from_entry(&entry, &resolver, &fixture_path).unwrap();
+
+    let analyzer = Analyzer::default();
+    let result = analyzer.analyze_graph(&graph);
+    analyzer.analyze(&mut graph);
+
+    assert_eq!(result.cycles, graph.get_cycles());
+    assert_eq!(result.metrics.len(), graph.node_count());
+
+    for (id, node) in graph.nodes() {
+        assert_eq!(result.metrics[id], node.metrics, "metrics of {}", id);
+
+        let analysis_flags: Vec<_> = node
+            .flags
+            .iter()
+            .filter(|f| !matches!(f, NodeFlag::EntryPoint | NodeFlag::Orphan))
+            .collect();
+        assert_eq!(
+            result.flags[id].iter().collect::<Vec<_>>(),
+            analysis_flags,
+            "flags of {}",
+            id
+        );
+    }
+}
+
+/// Graph with caller-chosen node IDs, for exercising the analyzer passes.
+struct NamedGraph {
+    graph: DiGraph<String, ()>,
+    entries: Vec<NodeIndex>,
+}
+
+impl DependencyGraphLike for NamedGraph {
+    type Node = String;
+    type Edge = ();
+
+    fn graph(&self) -> &DiGraph<String, ()> {
+        &self.graph
+    }
+
+    fn node_id(&self, idx: NodeIndex) -> &str {
+        &self.graph[idx]
+    }
+
+    fn entry_points(&self) -> Vec<NodeIndex> {
+        self.entries.clone()
+    }
+}
+
+/// Tests that duplicate node IDs in external graphs do not panic.
+#[test]
+fn analyze_graph_duplicate_ids() {
+    let mut graph = DiGraph::new();
+    let main = graph.add_node("main.less".to_string());
+    let first = graph.add_node("vars.less".to_string());
+    let second = graph.add_node("vars.less".to_string());
+    let leaf = graph.add_node("leaf.less".to_string());
+    graph.add_edge(main, first, ());
+    graph.add_edge(first, second, ());
+    graph.add_edge(second, leaf, ());
+
+    let graph = NamedGraph {
+        graph,
+        entries: vec![main],
+    };
+
+    let metrics = compute_metrics(&graph);
+    assert_eq!(metrics.len(), 3);
+    assert_eq!(metrics["vars.less"].depth, 2);
+    assert_eq!(metrics["leaf.less"].depth, 3);
+
+    let reachability = calculate_entry_reachability(&graph);
+    assert_eq!(reachability.len(), 3);
+    assert_eq!(reachability["leaf.less"], vec!["main.less".to_string()]);
+
+    Analyzer::default().analyze_graph(&graph);
+}
@@ -17,7 +17,8 @@ interface UseGraphDataReturn {
 /**
  * Hook for loading graph data from the server API or file upload.
  * When served by sass-dep's embedded server, it automatically fetches
- * from /api/data. Otherwise, it waits for file upload.
+ * from /api/data, or from /api/:project/data when a `?project=` query
+ * parameter is present. Otherwise, it waits for file upload.
  * @returns Object with data, loading state, error, and setData function
  */
 export function useGraphData(): UseGraphDataReturn {
@@ -28,11 +29,13 @@ export function useGraphData(): UseGraphDataReturn {
 	// Try to fetch from API on mount (for --web mode)
 	useEffect(() => {
 		/**
-		 * Fetches graph data from the /api/data endpoint.
+		 * Fetches graph data from the data endpoint of the selected project.
 		 */
 		async function fetchFromApi(): Promise<void> {
 			try {
-				const response = await fetch("/api/data");
+				const project = new URLSearchParams(window.location.search).get("project");
+				const endpoint = project ? `/api/${encodeURIComponent(project)}/data` : "/api/data";
+				const response = await fetch(endpoint);
 
 				if (response.ok) {
 					const json = await response.json();